@@ -0,0 +1,178 @@
+use anyhow::Result;
+use clap::Args;
+use day1::{calibrate, calibration_sum, Calibration, Digit, Locale, Scanner, Vocabulary};
+use inputs::Input;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+#[derive(Args)]
+pub struct CalibrateArgs {
+    /// The language to read digit words in: en, de, fr or es
+    #[arg(long, default_value = "en")]
+    locale: Locale,
+
+    /// Also read number words, such as "twelve" and "twenty"
+    #[arg(long)]
+    numbers: bool,
+
+    /// Read words from a TOML file of `word = value` pairs instead
+    #[arg(long, conflicts_with_all = ["locale", "numbers"])]
+    vocabulary: Option<PathBuf>,
+
+    /// Calibrate this input file, or `-` for stdin, instead of day 1's
+    #[arg(long)]
+    input: Option<Input>,
+
+    /// Print the digits found on every line, then the sum
+    #[arg(long)]
+    report: bool,
+
+    /// Print the digits found on every line as CSV
+    #[arg(long, conflicts_with = "report")]
+    csv: bool,
+
+    /// Leave out lines without a digit instead of failing on them
+    #[arg(long)]
+    skip_missing: bool,
+}
+
+pub fn run(args: CalibrateArgs) -> Result<()> {
+    let vocabulary = match &args.vocabulary {
+        Some(path) => Vocabulary::read(path)?,
+        None if args.numbers => Vocabulary::numbers(args.locale),
+        None => Vocabulary::digits(args.locale),
+    };
+    let input = args.input.unwrap_or_else(|| Input::day(1));
+    let lines: Vec<String> = input.read()?.lines().map(String::from).collect();
+    let scanner = Scanner::new(&vocabulary)?;
+
+    if !args.report && !args.csv && !args.skip_missing {
+        println!("{}", calibration_sum(&lines, &scanner)?);
+        return Ok(());
+    }
+
+    let calibrations: Vec<Calibration> = calibrate(&lines, &scanner)
+        .filter(|calibration| !args.skip_missing || calibration.digits.is_some())
+        .collect();
+
+    if args.csv {
+        print!("{}", csv(&calibrations));
+    } else if args.report {
+        print!("{}", table(&calibrations));
+    } else {
+        println!("{}", sum(&calibrations));
+    }
+
+    Ok(())
+}
+
+fn sum(calibrations: &[Calibration]) -> u32 {
+    calibrations.iter().filter_map(Calibration::value).sum()
+}
+
+/// A row per line, then the sum of the lines with a digit.
+fn table(calibrations: &[Calibration]) -> String {
+    let mut table = format!(
+        "{:>6}  {:>5}  {:>6}  {:>6}  {:>5}  {:>6}  {:>6}  {:>5}\n",
+        "Line", "First", "Offset", "Source", "Last", "Offset", "Source", "Value"
+    );
+
+    for calibration in calibrations {
+        match calibration.digits {
+            Some((first, last)) => writeln!(
+                table,
+                "{:>6}  {:>5}  {:>6}  {:>6}  {:>5}  {:>6}  {:>6}  {:>5}",
+                calibration.line,
+                first.value,
+                first.offset,
+                first.source,
+                last.value,
+                last.offset,
+                last.source,
+                first.leading() * 10 + last.trailing(),
+            ),
+            None => writeln!(table, "{:>6}  no digit", calibration.line),
+        }
+        .expect("writing to a string can't fail");
+    }
+
+    let missing = calibrations
+        .iter()
+        .filter(|calibration| calibration.digits.is_none())
+        .count();
+    writeln!(table, "{:>6}  {:>51}", "", sum(calibrations))
+        .expect("writing to a string can't fail");
+    if missing > 0 {
+        writeln!(table, "lines without a digit: {}", missing)
+            .expect("writing to a string can't fail");
+    }
+
+    table
+}
+
+/// A header and a record per line, with empty fields for lines without a
+/// digit.
+fn csv(calibrations: &[Calibration]) -> String {
+    let mut csv =
+        String::from("line,first,first_offset,first_source,last,last_offset,last_source,value\n");
+
+    for calibration in calibrations {
+        let (first, last) = calibration.digits.unzip();
+        let value = calibration.value().map(|value| value.to_string());
+        writeln!(
+            csv,
+            "{},{},{},{}",
+            calibration.line,
+            fields(first),
+            fields(last),
+            value.unwrap_or_default()
+        )
+        .expect("writing to a string can't fail");
+    }
+
+    csv
+}
+
+fn fields(digit: Option<Digit>) -> String {
+    match digit {
+        Some(digit) => format!("{},{},{}", digit.value, digit.offset, digit.source),
+        None => String::from(",,"),
+    }
+}
+
+#[cfg(test)]
+mod calibrate_tests {
+    use super::*;
+
+    fn calibrations(lines: &[&str]) -> Vec<Calibration> {
+        let lines: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+        let scanner = Scanner::new(&Vocabulary::digits(Locale::English)).unwrap();
+
+        calibrate(&lines, &scanner).collect()
+    }
+
+    #[test]
+    fn exports_csv() {
+        assert_eq!(
+            csv(&calibrations(&["xtwone3", "nada", "7"])),
+            "line,first,first_offset,first_source,last,last_offset,last_source,value\n\
+             1,2,1,word,3,6,digit,23\n\
+             2,,,,,,,\n\
+             3,7,0,digit,7,0,digit,77\n"
+        );
+    }
+
+    #[test]
+    fn reports_lines_without_digits() {
+        let table = table(&calibrations(&["1abc2", "nada"]));
+        let rows: Vec<&str> = table.lines().collect();
+
+        assert_eq!(rows.len(), 5);
+        assert!(rows[1]
+            .split_whitespace()
+            .eq(["1", "1", "0", "digit", "2", "4", "digit", "12"]));
+        assert_eq!(rows[2].trim(), "2  no digit");
+        assert_eq!(rows[3].trim(), "12");
+        assert_eq!(rows[4], "lines without a digit: 1");
+    }
+}
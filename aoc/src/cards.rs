@@ -0,0 +1,34 @@
+use anyhow::Result;
+use clap::Args;
+use day4::{cascade, Day4};
+use inputs::{InFile, Input, Solution};
+
+#[derive(Args)]
+pub struct CardsArgs {
+    /// Play out this input file, or `-` for stdin, instead of day 4's
+    #[arg(long)]
+    input: Option<Input>,
+}
+
+/// Prints how many copies of each card there were and which cards won them,
+/// then the total.
+pub fn run(args: CardsArgs) -> Result<()> {
+    let input = args.input.unwrap_or_else(|| Input::day(4));
+    let cards = Day4::parse(&input.read()?).in_file(&input.name())?;
+    let copies = cascade(&cards)?;
+
+    println!("{:>5}  {:>12}  Won from", "Card", "Copies");
+    for won in &copies {
+        let won_from: Vec<String> = won
+            .won_from
+            .iter()
+            .map(|(id, copies)| format!("{} from {}", copies, id))
+            .collect();
+        let row = format!("{:>5}  {:>12}  {}", won.id, won.copies, won_from.join(", "));
+        println!("{}", row.trim_end());
+    }
+
+    println!("{:>5}  {:>12}", "", Day4::part_two(&cards)?);
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anyhow::{anyhow, Result};
+use inputs::{solve_input, Input, Part, Report, Solution};
+
+/// A registered day, with its `Solution` erased so the runner can dispatch by number.
+pub struct Day {
+    pub day: u8,
+    pub solve: fn(&Input, &[Part]) -> Result<Report>,
+}
+
+impl Day {
+    const fn new<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            solve: solve_input::<S>,
+        }
+    }
+}
+
+pub static DAYS: &[Day] = &[
+    Day::new::<day1::Day1>(),
+    Day::new::<day2::Day2>(),
+    Day::new::<day3::Day3>(),
+    Day::new::<day4::Day4>(),
+    Day::new::<day5::Day5>(),
+    Day::new::<day6::Day6>(),
+    Day::new::<day7::Day7>(),
+    Day::new::<day8::Day8>(),
+    Day::new::<day9::Day9>(),
+];
+
+pub fn find(day: u8) -> Result<&'static Day> {
+    DAYS.iter()
+        .find(|d| d.day == day)
+        .ok_or_else(|| anyhow!("day {} has no solution yet", day))
+}
+
+/// Solves each of `days` against its `input`, carrying on past days that
+/// fail so that one unsolved day doesn't hide the rest.
+pub fn solve_each<'a>(
+    days: &[&'a Day],
+    input: impl Fn(&Day) -> Input,
+    parts: &[Part],
+) -> Vec<(&'a Day, Result<Report>)> {
+    days.iter()
+        .map(|&day| (day, (day.solve)(&input(day), parts)))
+        .collect()
+}
@@ -0,0 +1,198 @@
+use anyhow::{anyhow, Result};
+use clap::Args;
+use day2::{
+    log_likelihood, minimum_bag, most_likely_bag, possible_sum, possible_with_one_more, Bag, Day2,
+    Stats,
+};
+use inputs::{InFile, Input, Solution};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+#[derive(Args)]
+pub struct GamesArgs {
+    /// The cubes in the bag, written like a round
+    #[arg(long, default_value = "12 red, 13 green, 14 blue")]
+    bag: Bag,
+
+    /// Read the bag from a TOML file of `colour = count` pairs instead
+    #[arg(long, conflicts_with = "bag")]
+    bag_file: Option<PathBuf>,
+
+    /// Check the games in this input file, or `-` for stdin, instead of day 2's
+    #[arg(long)]
+    input: Option<Input>,
+
+    /// Print the smallest bag that makes every game possible
+    #[arg(long)]
+    minimum: bool,
+
+    /// Print the games that one more cube of this colour would make possible
+    #[arg(long, value_name = "COLOUR", conflicts_with = "minimum")]
+    add: Option<String>,
+
+    /// Print statistics about the draws, and the games the bag rules out or
+    /// makes unlikely
+    #[arg(long, conflicts_with_all = ["minimum", "add"])]
+    stats: bool,
+
+    /// The p-value under which the stats call a game implausible
+    #[arg(long, default_value_t = 0.01, requires = "stats")]
+    threshold: f64,
+
+    /// Print how likely the bag made each game, as a natural log
+    #[arg(long, conflicts_with_all = ["minimum", "add", "stats"])]
+    likelihood: bool,
+
+    /// Print the bag that most likely produced the games
+    #[arg(long, conflicts_with_all = ["minimum", "add", "stats", "likelihood"])]
+    infer: bool,
+
+    /// The most cubes of one colour a bag may have when inferring it
+    #[arg(long, default_value_t = 100, requires = "infer")]
+    max_cubes: u32,
+
+    /// Print the statistics as JSON instead
+    #[arg(long, requires = "stats")]
+    json: bool,
+}
+
+pub fn run(args: GamesArgs) -> Result<()> {
+    let bag = match &args.bag_file {
+        Some(path) => Bag::read(path)?,
+        None => args.bag,
+    };
+    let input = args.input.unwrap_or_else(|| Input::day(2));
+    let games = Day2::parse(&input.read()?).in_file(&input.name())?;
+
+    if args.stats {
+        let stats = Stats::new(&games, &bag, args.threshold);
+        if args.json {
+            println!("{}", to_json(&stats)?);
+        } else {
+            print_stats(&stats);
+        }
+    } else if args.likelihood {
+        for game in &games {
+            println!("{}  {:.4}", game.id(), log_likelihood(game, &bag));
+        }
+    } else if args.infer {
+        let (bag, score) = most_likely_bag(&games, args.max_cubes).ok_or_else(|| {
+            anyhow!(
+                "a game draws more than {} cubes of a colour",
+                args.max_cubes
+            )
+        })?;
+        println!("{}  {:.4}", bag, score);
+    } else if args.minimum {
+        println!("{}", minimum_bag(&games));
+    } else if let Some(colour) = &args.add {
+        for game in possible_with_one_more(&games, &bag, colour) {
+            println!("{}", game.id());
+        }
+    } else {
+        println!("{}", possible_sum(&games, &bag));
+    }
+
+    Ok(())
+}
+
+/// Prints a table of the draws of each colour, then how many rounds the games
+/// had and which games the bag rules out or makes unlikely.
+fn print_stats(stats: &Stats) {
+    println!("{} games", stats.games);
+    println!();
+    println!(
+        "{:<10}  {:>6}  {:>4}  {:>6}",
+        "Colour", "Rounds", "Max", "Mean"
+    );
+    for colour in &stats.colours {
+        println!(
+            "{:<10}  {:>6}  {:>4}  {:>6.2}",
+            colour.colour, colour.rounds, colour.max, colour.mean
+        );
+    }
+
+    println!();
+    println!("{:<10}  {:>6}", "Rounds", "Games");
+    for (rounds, games) in &stats.rounds {
+        println!("{:<10}  {:>6}", rounds, games);
+    }
+
+    println!();
+    if stats.impossible.is_empty() {
+        println!("every game is possible");
+    }
+    for (id, shortfall) in &stats.impossible {
+        println!("game {} needs {} more", id, shortfall);
+    }
+
+    println!();
+    if stats.implausible.is_empty() {
+        println!("every possible game is plausible");
+    }
+    for (id, p) in &stats.implausible {
+        println!("game {} is implausible, p = {:.2e}", id, p);
+    }
+}
+
+#[derive(Serialize)]
+struct StatsJson<'a> {
+    games: usize,
+    colours: Vec<ColourJson<'a>>,
+    rounds: &'a BTreeMap<usize, usize>,
+    impossible: Vec<ImpossibleJson<'a>>,
+    implausible: Vec<ImplausibleJson>,
+}
+
+#[derive(Serialize)]
+struct ColourJson<'a> {
+    colour: &'a str,
+    rounds: usize,
+    max: u32,
+    mean: f64,
+}
+
+#[derive(Serialize)]
+struct ImpossibleJson<'a> {
+    id: u32,
+    shortfall: BTreeMap<&'a str, u32>,
+}
+
+#[derive(Serialize)]
+struct ImplausibleJson {
+    id: u32,
+    p_value: f64,
+}
+
+fn to_json(stats: &Stats) -> Result<String> {
+    let json = StatsJson {
+        games: stats.games,
+        colours: stats
+            .colours
+            .iter()
+            .map(|colour| ColourJson {
+                colour: &colour.colour,
+                rounds: colour.rounds,
+                max: colour.max,
+                mean: colour.mean,
+            })
+            .collect(),
+        rounds: &stats.rounds,
+        impossible: stats
+            .impossible
+            .iter()
+            .map(|(id, shortfall)| ImpossibleJson {
+                id: *id,
+                shortfall: shortfall.cubes().collect(),
+            })
+            .collect(),
+        implausible: stats
+            .implausible
+            .iter()
+            .map(|&(id, p_value)| ImplausibleJson { id, p_value })
+            .collect(),
+    };
+
+    Ok(serde_json::to_string_pretty(&json)?)
+}
@@ -0,0 +1,206 @@
+use anyhow::Result;
+use clap::{Args, Parser, Subcommand};
+use client::{Client, Config, Verdict};
+use inputs::{print_report, Answers, Input, Part};
+use std::path::Path;
+
+mod calibrate;
+mod cards;
+mod days;
+mod games;
+mod scaffold;
+mod timing;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day, or every day with --all
+    Run(RunArgs),
+    /// Scaffold the crate, input and examples for a new day
+    New(NewArgs),
+    /// Download a day's input, unless it's already cached
+    Fetch(FetchArgs),
+    /// Solve one part of a day and submit the answer
+    Submit(SubmitArgs),
+    /// Sum day 1's calibration values, reading digits in another vocabulary
+    Calibrate(calibrate::CalibrateArgs),
+    /// Check day 2's games against another bag of cubes
+    Games(games::GamesArgs),
+    /// Trace which of day 4's scratchcards won copies of which
+    Cards(cards::CardsArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// The day to solve
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+
+    /// Solve every day that has a solution
+    #[arg(long)]
+    all: bool,
+
+    /// Only solve the given part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Solve this input file, or `-` for stdin, instead of the day's default
+    #[arg(long, conflicts_with = "all")]
+    input: Option<Input>,
+
+    /// Print how long parsing and each part took
+    #[arg(long)]
+    time: bool,
+
+    /// Print answers and timings as JSON instead
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// The day to scaffold
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// The day to download the input for
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// The day to submit an answer for
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// The part to submit an answer for
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+}
+
+fn main() -> Result<()> {
+    match Cli::parse().command {
+        Command::Run(args) => run(args),
+        Command::New(args) => scaffold::new_day(root(), args.day),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+        Command::Calibrate(args) => calibrate::run(args),
+        Command::Games(args) => games::run(args),
+        Command::Cards(args) => cards::run(args),
+    }
+}
+
+/// The root of the repository, where the runner keeps its config and state.
+fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc lives inside the workspace")
+}
+
+fn fetch(args: FetchArgs) -> Result<()> {
+    let input = Input::day(args.day);
+    let path = input.path().expect("a day's input is a file");
+    Client::new(Config::load(root())?).fetch_input(args.day, path)?;
+    println!("fetched day {} input to {}", args.day, path.display());
+
+    Ok(())
+}
+
+fn submit(args: SubmitArgs) -> Result<()> {
+    let part = part(args.part);
+    let report = (days::find(args.day)?.solve)(&Input::day(args.day), &[part])?;
+    let answer = report
+        .get(part)
+        .expect("the part was solved")
+        .answer
+        .to_string();
+    let submission = Client::new(Config::load(root())?).submit(args.day, args.part, &answer)?;
+
+    let verdict = match submission.verdict {
+        Verdict::Correct => "is the right answer ✔".to_string(),
+        Verdict::TooHigh => "is too high ✘".to_string(),
+        Verdict::TooLow => "is too low ✘".to_string(),
+        Verdict::Wrong => "isn't the right answer ✘".to_string(),
+        Verdict::Wait(wait) => format!("wasn't checked: wait {}s to submit again", wait.as_secs()),
+    };
+    let known = if submission.known {
+        " (known from an earlier submission)"
+    } else {
+        ""
+    };
+    println!(
+        "day {} {}: {} {}{}",
+        args.day,
+        part.name(),
+        answer,
+        verdict,
+        known
+    );
+
+    Ok(())
+}
+
+fn part(number: u8) -> Part {
+    match number {
+        1 => Part::One,
+        _ => Part::Two,
+    }
+}
+
+fn run(args: RunArgs) -> Result<()> {
+    let parts = match args.part {
+        Some(n) => vec![part(n)],
+        None => Part::BOTH.to_vec(),
+    };
+
+    let days = match args.day {
+        Some(day) => vec![days::find(day)?],
+        None => days::DAYS.iter().collect(),
+    };
+
+    let answers = Answers::read()?;
+    let input = |day: &days::Day| match &args.input {
+        Some(input) => input.clone(),
+        None => Input::day(day.day),
+    };
+    let mut reports = Vec::new();
+
+    for (day, report) in days::solve_each(&days, input, &parts) {
+        // A day that fails only stops the run when it's the one asked for.
+        let report = match report {
+            Ok(report) => report,
+            Err(e) if args.all => {
+                eprintln!("day {}: {:#}", day.day, e);
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+
+        if !args.json {
+            if !reports.is_empty() {
+                println!();
+            }
+            print_report(&report, answers.for_input(day.day, &input(day)));
+        }
+        reports.push(report);
+    }
+
+    if args.json {
+        println!("{}", timing::to_json(&reports)?);
+    } else if args.time {
+        println!();
+        timing::print_table(&reports);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,302 @@
+use anyhow::{anyhow, Result};
+use std::fs;
+use std::path::Path;
+
+const CARGO_TOML: &str = r#"[package]
+name = "day{day}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+anyhow = { workspace = true }
+inputs = { path = "../../inputs" }
+"#;
+
+const MAIN_RS: &str = r#"use anyhow::Result;
+use day{day}::Day{day};
+
+fn main() -> Result<()> {
+    inputs::run::<Day{day}>()
+}
+"#;
+
+const LIB_RS: &str = r#"use anyhow::{anyhow, Result};
+use inputs::{Answer, Lines, Solution};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    const DAY: u8 = {day};
+
+    type Parsed = Lines;
+
+    fn parse(input: &str) -> Result<Lines> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part_one(lines: &Lines) -> Result<Answer> {
+        part_one(lines).map(Answer::from)
+    }
+
+    fn part_two(lines: &Lines) -> Result<Answer> {
+        part_two(lines).map(Answer::from)
+    }
+}
+
+fn part_one(_lines: &Lines) -> Result<u32> {
+    Err(anyhow!("part one isn't solved yet"))
+}
+
+fn part_two(_lines: &Lines) -> Result<u32> {
+    Err(anyhow!("part two isn't solved yet"))
+}
+
+// Drop each `#[ignore]` once its answer or example is filled in.
+#[cfg(test)]
+mod day{day}_tests {
+    use super::*;
+    use inputs::{check_answers, check_examples, Part};
+
+    #[test]
+    #[ignore = "no answers yet"]
+    fn part_1() {
+        check_answers::<Day{day}>(Part::One).unwrap();
+    }
+
+    #[test]
+    #[ignore = "no answers yet"]
+    fn part_2() {
+        check_answers::<Day{day}>(Part::Two).unwrap();
+    }
+
+    #[test]
+    #[ignore = "no answers yet"]
+    fn example_part_1() {
+        check_examples::<Day{day}>(Part::One).unwrap();
+    }
+
+    #[test]
+    #[ignore = "no answers yet"]
+    fn example_part_2() {
+        check_examples::<Day{day}>(Part::Two).unwrap();
+    }
+}
+"#;
+
+const EXAMPLES_TOML: &str = r#"[[example]]
+name = "example"
+# part_one =
+# part_two =
+input = '''
+'''
+"#;
+
+const ANSWERS_TOML: &str = r#"
+[day{day}]
+"day{day}.txt" = {}
+"#;
+
+/// Creates the `solutions/dayN` crate for `day` under the repository at
+/// `root`, along with its input, examples and answers, and registers it with
+/// the runner.
+pub fn new_day(root: &Path, day: u8) -> Result<()> {
+    let crate_dir = root.join("solutions").join(format!("day{}", day));
+    if crate_dir.exists() {
+        return Err(anyhow!("{} already exists", crate_dir.display()));
+    }
+
+    fs::create_dir_all(crate_dir.join("src"))?;
+    fs::write(crate_dir.join("Cargo.toml"), fill(CARGO_TOML, day))?;
+    fs::write(crate_dir.join("src").join("main.rs"), fill(MAIN_RS, day))?;
+    fs::write(crate_dir.join("src").join("lib.rs"), fill(LIB_RS, day))?;
+    println!("created {}", crate_dir.display());
+
+    let inputs_dir = root.join("inputs");
+    create_if_missing(&inputs_dir.join(format!("day{}.txt", day)), "")?;
+    create_if_missing(
+        &inputs_dir.join("examples").join(format!("day{}.toml", day)),
+        EXAMPLES_TOML,
+    )?;
+
+    let answers = inputs_dir.join("answers.toml");
+    let mut contents = fs::read_to_string(&answers)?;
+    if !contents.contains(&format!("[day{}]", day)) {
+        contents.push_str(&fill(ANSWERS_TOML, day));
+        fs::write(&answers, contents)?;
+        println!("registered day {} in {}", day, answers.display());
+    }
+
+    update(
+        &root.join("aoc").join("Cargo.toml"),
+        "day",
+        day,
+        &format!(
+            "day{day} = {{ path = \"../solutions/day{day}\" }}",
+            day = day
+        ),
+    )?;
+    update(
+        &root.join("aoc").join("src").join("days.rs"),
+        "    Day::new::<day",
+        day,
+        &format!("    Day::new::<day{day}::Day{day}>(),", day = day),
+    )?;
+
+    Ok(())
+}
+
+fn fill(template: &str, day: u8) -> String {
+    template.replace("{day}", &day.to_string())
+}
+
+fn create_if_missing(path: &Path, contents: &str) -> Result<()> {
+    if path.exists() {
+        println!("kept existing {}", path.display());
+    } else {
+        fs::write(path, contents)?;
+        println!("created {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn update(path: &Path, prefix: &str, day: u8, line: &str) -> Result<()> {
+    let contents = fs::read_to_string(path)?;
+    fs::write(path, insert_in_order(&contents, prefix, day, line)?)?;
+    println!("registered day {} in {}", day, path.display());
+
+    Ok(())
+}
+
+/// Inserts `line` among the lines made of `prefix` followed by a day number,
+/// keeping them in day order.
+fn insert_in_order(contents: &str, prefix: &str, day: u8, line: &str) -> Result<String> {
+    let mut lines: Vec<&str> = contents.lines().collect();
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| {
+            let digits: String = l
+                .strip_prefix(prefix)?
+                .chars()
+                .take_while(char::is_ascii_digit)
+                .collect();
+            Some((i, digits.parse().ok()?))
+        })
+        .collect();
+
+    if days.iter().any(|&(_, d)| d == day) {
+        return Err(anyhow!("day {} is already registered", day));
+    }
+
+    let &(last, _) = days
+        .last()
+        .ok_or_else(|| anyhow!("no days to register day {} alongside", day))?;
+    let index = days
+        .iter()
+        .find(|&&(_, d)| d > day)
+        .map_or(last + 1, |&(i, _)| i);
+    lines.insert(index, line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod scaffold_tests {
+    use super::*;
+    use crate::days::{self, Day};
+    use inputs::{solve_input, Answer, Input, Lines, Part, Solution};
+
+    /// What a freshly scaffolded day solves to.
+    struct Unsolved;
+
+    impl Solution for Unsolved {
+        const DAY: u8 = 10;
+
+        type Parsed = Lines;
+
+        fn parse(input: &str) -> Result<Lines> {
+            Ok(input.lines().map(String::from).collect())
+        }
+
+        fn part_one(_lines: &Lines) -> Result<Answer> {
+            Err(anyhow!("part one isn't solved yet"))
+        }
+
+        fn part_two(_lines: &Lines) -> Result<Answer> {
+            Err(anyhow!("part two isn't solved yet"))
+        }
+    }
+
+    #[test]
+    fn inserts_in_day_order() {
+        let contents = "[dependencies]\nday1 = 1\nday3 = 3\n";
+
+        assert_eq!(
+            insert_in_order(contents, "day", 2, "day2 = 2").unwrap(),
+            "[dependencies]\nday1 = 1\nday2 = 2\nday3 = 3\n"
+        );
+        assert_eq!(
+            insert_in_order(contents, "day", 10, "day10 = 10").unwrap(),
+            "[dependencies]\nday1 = 1\nday3 = 3\nday10 = 10\n"
+        );
+        assert!(insert_in_order(contents, "day", 3, "day3 = 3").is_err());
+    }
+
+    #[test]
+    fn scaffolds_a_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::create_dir_all(root.join("inputs/examples")).unwrap();
+        fs::write(
+            root.join("aoc/Cargo.toml"),
+            "day9 = { path = \"../solutions/day9\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/src/days.rs"),
+            "    Day::new::<day9::Day9>(),\n",
+        )
+        .unwrap();
+        fs::write(root.join("inputs/answers.toml"), "[day9]\n").unwrap();
+
+        new_day(&root, 10).unwrap();
+
+        let lib = fs::read_to_string(root.join("solutions/day10/src/lib.rs")).unwrap();
+        assert!(lib.contains("impl Solution for Day10 {\n    const DAY: u8 = 10;"));
+        assert!(lib.contains("mod day10_tests {"));
+        assert!(root.join("inputs/day10.txt").exists());
+        assert!(root.join("inputs/examples/day10.toml").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("inputs/answers.toml")).unwrap(),
+            "[day9]\n\n[day10]\n\"day10.txt\" = {}\n"
+        );
+        assert!(fs::read_to_string(root.join("aoc/src/days.rs"))
+            .unwrap()
+            .ends_with("    Day::new::<day10::Day10>(),\n"));
+        assert!(new_day(&root, 10).is_err());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn running_every_day_carries_on_past_a_new_one() {
+        let unsolved = Day {
+            day: 10,
+            solve: solve_input::<Unsolved>,
+        };
+        let solved = days::find(1).unwrap();
+        let results = days::solve_each(
+            &[&unsolved, solved],
+            |_| Input::Text("1abc2".to_string()),
+            &[Part::One],
+        );
+
+        let [(_, Err(e)), (day, Ok(report))] = &results[..] else {
+            panic!("expected day 10 to fail and day 1 to be solved");
+        };
+        assert_eq!(e.to_string(), "part one isn't solved yet");
+        assert_eq!(day.day, 1);
+        assert_eq!(report.get(Part::One).unwrap().answer, Answer::from(12u32));
+    }
+}
@@ -0,0 +1,80 @@
+use anyhow::Result;
+use inputs::{Answer, Part, Report};
+use serde::Serialize;
+use std::time::Duration;
+
+/// Prints how long each phase of each day took, with a total row when there's
+/// more than one day.
+pub fn print_table(reports: &[Report]) {
+    println!(
+        "{:>3}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "Day", "Parse", "Part one", "Part two", "Total"
+    );
+
+    for report in reports {
+        println!(
+            "{:>3}  {:>10}  {:>10}  {:>10}  {:>10}",
+            report.day,
+            format_duration(Some(report.parse_time)),
+            format_duration(part_time(report, Part::One)),
+            format_duration(part_time(report, Part::Two)),
+            format_duration(Some(report.total_time())),
+        );
+    }
+
+    if reports.len() > 1 {
+        let total = reports.iter().map(Report::total_time).sum();
+        println!(
+            "{:>3}  {:>10}  {:>10}  {:>10}  {:>10}",
+            "",
+            "",
+            "",
+            "",
+            format_duration(Some(total))
+        );
+    }
+}
+
+#[derive(Serialize)]
+struct DayTiming<'a> {
+    day: u8,
+    part_one: Option<&'a Answer>,
+    part_two: Option<&'a Answer>,
+    parse_ms: f64,
+    part_one_ms: Option<f64>,
+    part_two_ms: Option<f64>,
+    total_ms: f64,
+}
+
+/// Renders the answers and timings of each day as a JSON array.
+pub fn to_json(reports: &[Report]) -> Result<String> {
+    let timings: Vec<DayTiming> = reports
+        .iter()
+        .map(|report| DayTiming {
+            day: report.day,
+            part_one: report.get(Part::One).map(|solved| &solved.answer),
+            part_two: report.get(Part::Two).map(|solved| &solved.answer),
+            parse_ms: millis(report.parse_time),
+            part_one_ms: part_time(report, Part::One).map(millis),
+            part_two_ms: part_time(report, Part::Two).map(millis),
+            total_ms: millis(report.total_time()),
+        })
+        .collect();
+
+    Ok(serde_json::to_string_pretty(&timings)?)
+}
+
+fn part_time(report: &Report, part: Part) -> Option<Duration> {
+    report.get(part).map(|solved| solved.time)
+}
+
+fn format_duration(duration: Option<Duration>) -> String {
+    match duration {
+        Some(duration) => format!("{:.2?}", duration),
+        None => "-".to_string(),
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
@@ -0,0 +1,66 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use day2::Day2;
+use inputs::{Part, Solution};
+use std::fmt::Write;
+
+/// A log of `games` random games, shaped like the puzzle's, from a fixed seed.
+fn generate(games: u32) -> String {
+    let mut state: u64 = 0x2023_1202;
+    let mut random = |below: u32| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state % below as u64) as u32
+    };
+
+    let mut log = String::new();
+    for id in 1..=games {
+        write!(log, "Game {}:", id).unwrap();
+        for round in 0..3 + random(4) {
+            let colours = ["red", "green", "blue"];
+            let first = random(3) as usize;
+            for i in 0..1 + random(3) as usize {
+                let separator = if i > 0 {
+                    ","
+                } else if round > 0 {
+                    ";"
+                } else {
+                    ""
+                };
+                let colour = colours[(first + i) % 3];
+                write!(log, "{} {} {}", separator, 1 + random(20), colour).unwrap();
+            }
+        }
+        log.push('\n');
+    }
+
+    log
+}
+
+/// Compares parsing into `Game`s and solving each part against adding up
+/// both parts in one streaming pass, on a million games.
+fn bench_stream(c: &mut Criterion) {
+    let log = generate(1_000_000);
+    let mut group = c.benchmark_group("day2-stream");
+    group
+        .sample_size(10)
+        .throughput(Throughput::Bytes(log.len() as u64));
+
+    group.bench_function("games", |b| {
+        b.iter(|| {
+            let games = Day2::parse(black_box(&log)).unwrap();
+            (
+                Day2::solve(Part::One, &games).unwrap(),
+                Day2::solve(Part::Two, &games).unwrap(),
+            )
+        })
+    });
+    group.bench_function("stream", |b| {
+        b.iter(|| day2::totals(black_box(log.as_bytes())).unwrap())
+    });
+
+    group.finish();
+}
+
+criterion_group!(stream, bench_stream);
+criterion_main!(stream);
@@ -0,0 +1,52 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use inputs::{read_examples, Input, Part, Solution};
+
+/// Benchmarks parsing and each part of `S`, against the day's input and every
+/// example. Examples only run the parts they have an answer for.
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("day{}", S::DAY));
+
+    let mut inputs = vec![(
+        "input".to_string(),
+        Input::checked_in(&format!("day{}.txt", S::DAY))
+            .read()
+            .unwrap(),
+        Part::BOTH.to_vec(),
+    )];
+    for example in read_examples(S::DAY).unwrap() {
+        let parts = Part::BOTH
+            .into_iter()
+            .filter(|&part| example.expected(part).is_some())
+            .collect();
+        inputs.push((example.name, example.input, parts));
+    }
+
+    for (name, input, parts) in &inputs {
+        group.bench_with_input(BenchmarkId::new("parse", name), input, |b, input| {
+            b.iter(|| S::parse(black_box(input)).unwrap())
+        });
+
+        let parsed = S::parse(input).unwrap();
+        for &part in parts {
+            group.bench_with_input(BenchmarkId::new(part.name(), name), &parsed, |b, parsed| {
+                b.iter(|| S::solve(part, black_box(parsed)).unwrap())
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    days,
+    bench_day::<day1::Day1>,
+    bench_day::<day2::Day2>,
+    bench_day::<day3::Day3>,
+    bench_day::<day4::Day4>,
+    bench_day::<day5::Day5>,
+    bench_day::<day6::Day6>,
+    bench_day::<day7::Day7>,
+    bench_day::<day8::Day8>,
+    bench_day::<day9::Day9>,
+);
+criterion_main!(days);
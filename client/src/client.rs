@@ -0,0 +1,231 @@
+use crate::{Config, RateLimiter, Verdict, Verdicts};
+use anyhow::{anyhow, Context, Result};
+use std::fs;
+use std::path::Path;
+
+/// The year every puzzle is fetched from.
+pub const YEAR: u16 = 2023;
+
+const USER_AGENT: &str = "advent-of-code-2023 aoc runner";
+
+/// The verdict on a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub verdict: Verdict,
+    /// Whether the verdict came from earlier ones instead of the site.
+    pub known: bool,
+}
+
+/// Talks to the puzzle site, one rate-limited request at a time.
+pub struct Client {
+    config: Config,
+    agent: ureq::Agent,
+    limiter: RateLimiter,
+}
+
+impl Client {
+    pub fn new(config: Config) -> Self {
+        let limiter = RateLimiter::new(config.state_dir.join("last-request"), config.min_interval);
+        let agent = ureq::AgentBuilder::new().user_agent(USER_AGENT).build();
+
+        Self {
+            config,
+            agent,
+            limiter,
+        }
+    }
+
+    /// Downloads the input for `day` to `path`, unless it's already there.
+    ///
+    /// An empty file, as left by `aoc new`, doesn't count as cached.
+    pub fn fetch_input(&self, day: u8, path: &Path) -> Result<()> {
+        if fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0) {
+            return Err(anyhow!(
+                "day {} input is already cached at {}",
+                day,
+                path.display()
+            ));
+        }
+
+        let url = self.url(day, "/input");
+        let request = self.agent.get(&url).set("Cookie", &self.cookie()?);
+        self.limiter.wait()?;
+        let input = read_response(&url, request.call())?;
+
+        fs::write(path, input).with_context(|| format!("couldn't write {}", path.display()))
+    }
+
+    /// Submits `answer` to part `level` of `day`, unless earlier verdicts
+    /// already say how it would do.
+    pub fn submit(&self, day: u8, level: u8, answer: &str) -> Result<Submission> {
+        let path = self.config.state_dir.join("verdicts.toml");
+        let mut verdicts = Verdicts::read(&path)?;
+        if let Some(verdict) = verdicts.known(day, level, answer) {
+            return Ok(Submission {
+                verdict,
+                known: true,
+            });
+        }
+
+        let url = self.url(day, "/answer");
+        let request = self.agent.post(&url).set("Cookie", &self.cookie()?);
+        self.limiter.wait()?;
+        let form = request.send_form(&[("level", &level.to_string()), ("answer", answer)]);
+        let reply = read_response(&url, form)?;
+        let verdict = Verdict::from_reply(&reply)
+            .with_context(|| format!("couldn't tell how {} did", answer))?;
+
+        verdicts.record(day, level, answer, verdict);
+        verdicts.write(&path)?;
+
+        Ok(Submission {
+            verdict,
+            known: false,
+        })
+    }
+
+    fn url(&self, day: u8, path: &str) -> String {
+        format!(
+            "{}/{}/day/{}{}",
+            self.config.base_url.trim_end_matches('/'),
+            YEAR,
+            day,
+            path
+        )
+    }
+
+    fn cookie(&self) -> Result<String> {
+        let session = self.config.session.as_deref().ok_or_else(|| {
+            anyhow!(
+                "no session cookie: set {} or `session` in aoc.toml",
+                crate::SESSION_VAR
+            )
+        })?;
+
+        Ok(format!("session={}", session))
+    }
+}
+
+fn read_response(url: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .with_context(|| format!("couldn't read response from {}", url)),
+        Err(ureq::Error::Status(status, response)) => Err(anyhow!(
+            "{} returned {}: {}",
+            url,
+            status,
+            response.into_string().unwrap_or_default().trim()
+        )),
+        Err(e) => Err(e).with_context(|| format!("couldn't reach {}", url)),
+    }
+}
+
+#[cfg(test)]
+mod client_tests {
+    use super::*;
+    use crate::mock::MockServer;
+    use std::time::Duration;
+
+    fn client(server: &MockServer, name: &str) -> (Client, std::path::PathBuf) {
+        let dir = std::env::temp_dir().join(format!("aoc-client-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let mut config = Config::new(&server.url, dir.join(".aoc"));
+        config.session = Some("cookie".to_string());
+        config.min_interval = Duration::ZERO;
+
+        (Client::new(config), dir)
+    }
+
+    #[test]
+    fn fetches_and_caches_inputs() {
+        let server = MockServer::serve(vec![(200, "1 2 3\n")]);
+        let (client, dir) = client(&server, "fetch");
+        let path = dir.join("day9.txt");
+        fs::write(&path, "").unwrap();
+
+        client.fetch_input(9, &path).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+
+        let request = server.request();
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.url, "/2023/day/9/input");
+        assert_eq!(request.cookie.as_deref(), Some("session=cookie"));
+
+        let e = client.fetch_input(9, &path).unwrap_err();
+        assert!(e.to_string().contains("already cached"), "{}", e);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn reports_failed_requests() {
+        let server = MockServer::serve(vec![(400, "Puzzle inputs differ by user.\n")]);
+        let (client, dir) = client(&server, "failed");
+        let path = dir.join("day9.txt");
+
+        let e = client.fetch_input(9, &path).unwrap_err();
+        assert!(
+            e.to_string()
+                .ends_with("returned 400: Puzzle inputs differ by user."),
+            "{}",
+            e
+        );
+        assert!(!path.exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn submits_answers_once() {
+        let server = MockServer::serve(vec![
+            (
+                200,
+                "<article><p>That's not the right answer; your answer is too high.</p></article>",
+            ),
+            (200, "<article><p>You have 45s left to wait.</p></article>"),
+            (200, "<article><p>That's the right answer!</p></article>"),
+        ]);
+        let (client, dir) = client(&server, "submit");
+
+        let submission = client.submit(4, 2, "9721256").unwrap();
+        assert_eq!(submission.verdict, Verdict::TooHigh);
+        assert!(!submission.known);
+        let request = server.request();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.url, "/2023/day/4/answer");
+        assert_eq!(request.body, "level=2&answer=9721256");
+
+        // Known too high, so the site isn't asked again.
+        let submission = client.submit(4, 2, "9999999").unwrap();
+        assert_eq!(submission.verdict, Verdict::TooHigh);
+        assert!(submission.known);
+
+        let submission = client.submit(4, 2, "9721255").unwrap();
+        assert_eq!(submission.verdict, Verdict::Wait(Duration::from_secs(45)));
+        let submission = client.submit(4, 2, "9721255").unwrap();
+        assert_eq!(submission.verdict, Verdict::Correct);
+        assert!(!submission.known);
+        assert_eq!(server.request().body, "level=2&answer=9721255");
+        assert_eq!(server.request().body, "level=2&answer=9721255");
+
+        let submission = client.submit(4, 2, "9721255").unwrap();
+        assert_eq!(submission.verdict, Verdict::Correct);
+        assert!(submission.known);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn needs_a_session() {
+        let (mut client, dir) = client(&MockServer::serve(vec![]), "session");
+        client.config.session = None;
+
+        let e = client.fetch_input(9, &dir.join("day9.txt")).unwrap_err();
+        assert!(e.to_string().starts_with("no session cookie"), "{}", e);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,88 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Holds the session cookie, overriding `session` in the config file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Overrides `base_url` in the config file, e.g. to point at a mock server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The least time to leave between requests unless the config file says
+/// otherwise, to go easy on the site.
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How to reach the puzzle site.
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub base_url: String,
+    pub session: Option<String>,
+    pub min_interval: Duration,
+    /// Where the client keeps what it remembers between runs.
+    pub state_dir: PathBuf,
+}
+
+/// The optional `aoc.toml` at the root of the repository:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// base_url = "https://adventofcode.com"
+/// min_interval_secs = 5
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    session: Option<String>,
+    base_url: Option<String>,
+    min_interval_secs: Option<u64>,
+}
+
+impl Config {
+    /// A config for the site at `base_url` with no session and the default
+    /// rate limit.
+    pub fn new(base_url: &str, state_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            base_url: base_url.to_string(),
+            session: None,
+            min_interval: DEFAULT_MIN_INTERVAL,
+            state_dir: state_dir.into(),
+        }
+    }
+
+    /// Reads `aoc.toml` in `root`, if there is one, then applies overrides
+    /// from the environment. State is kept in `root/.aoc`.
+    pub fn load(root: &Path) -> Result<Self> {
+        let path = root.join("aoc.toml");
+        let file: ConfigFile = if path.exists() {
+            let contents = fs::read_to_string(&path)
+                .with_context(|| format!("couldn't read config {}", path.display()))?;
+            toml::from_str(&contents)
+                .with_context(|| format!("couldn't parse config {}", path.display()))?
+        } else {
+            ConfigFile::default()
+        };
+
+        let mut config = Self::new(
+            file.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL),
+            root.join(".aoc"),
+        );
+        config.session = file.session;
+        if let Some(secs) = file.min_interval_secs {
+            config.min_interval = Duration::from_secs(secs);
+        }
+
+        if let Ok(session) = env::var(SESSION_VAR) {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var(BASE_URL_VAR) {
+            config.base_url = base_url;
+        }
+
+        Ok(config)
+    }
+}
@@ -0,0 +1,11 @@
+mod client;
+mod config;
+#[cfg(test)]
+mod mock;
+mod rate_limit;
+mod verdict;
+
+pub use client::{Client, Submission, YEAR};
+pub use config::{Config, BASE_URL_VAR, DEFAULT_BASE_URL, DEFAULT_MIN_INTERVAL, SESSION_VAR};
+pub use rate_limit::RateLimiter;
+pub use verdict::{Verdict, Verdicts};
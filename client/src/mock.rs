@@ -0,0 +1,59 @@
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use tiny_http::{Response, Server};
+
+/// A request the mock server received.
+#[derive(Debug)]
+pub struct Recorded {
+    pub method: String,
+    pub url: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+/// A local stand-in for the puzzle site that answers requests with canned
+/// responses, in order, and records what it was sent.
+pub struct MockServer {
+    pub url: String,
+    requests: Receiver<Recorded>,
+}
+
+impl MockServer {
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> Self {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr());
+        let (sender, requests) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let mut request = server.recv().unwrap();
+                let mut sent = String::new();
+                request.as_reader().read_to_string(&mut sent).unwrap();
+                let cookie = request
+                    .headers()
+                    .iter()
+                    .find(|header| header.field.equiv("Cookie"))
+                    .map(|header| header.value.to_string());
+
+                sender
+                    .send(Recorded {
+                        method: request.method().to_string(),
+                        url: request.url().to_string(),
+                        cookie,
+                        body: sent,
+                    })
+                    .unwrap();
+                request
+                    .respond(Response::from_string(body).with_status_code(status))
+                    .unwrap();
+            }
+        });
+
+        Self { url, requests }
+    }
+
+    /// The next request the server received.
+    pub fn request(&self) -> Recorded {
+        self.requests.recv().unwrap()
+    }
+}
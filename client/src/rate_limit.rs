@@ -0,0 +1,73 @@
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Spaces requests at least `interval` apart, across runs, by keeping the time
+/// of the last request in a file.
+#[derive(Clone, Debug)]
+pub struct RateLimiter {
+    path: PathBuf,
+    interval: Duration,
+}
+
+impl RateLimiter {
+    pub fn new(path: impl Into<PathBuf>, interval: Duration) -> Self {
+        Self {
+            path: path.into(),
+            interval,
+        }
+    }
+
+    /// Sleeps until a request is allowed, then records one as made now.
+    pub fn wait(&self) -> Result<()> {
+        if let Some(last) = self.last_request() {
+            let elapsed = SystemTime::now()
+                .duration_since(last)
+                .unwrap_or(Duration::ZERO);
+            if elapsed < self.interval {
+                thread::sleep(self.interval - elapsed);
+            }
+        }
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("couldn't create {}", dir.display()))?;
+        }
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis();
+        fs::write(&self.path, now.to_string())
+            .with_context(|| format!("couldn't write {}", self.path.display()))
+    }
+
+    fn last_request(&self) -> Option<SystemTime> {
+        let millis = fs::read_to_string(&self.path).ok()?.trim().parse().ok()?;
+        Some(UNIX_EPOCH + Duration::from_millis(millis))
+    }
+}
+
+#[cfg(test)]
+mod rate_limit_tests {
+    use super::*;
+    use std::time::Instant;
+
+    #[test]
+    fn spaces_requests() {
+        let path = std::env::temp_dir().join(format!("aoc-rate-limit-{}", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let limiter = RateLimiter::new(&path, Duration::from_millis(200));
+
+        let start = Instant::now();
+        limiter.wait().unwrap();
+        assert!(start.elapsed() < Duration::from_millis(200));
+
+        // A second limiter sharing the file, as the next run would.
+        RateLimiter::new(&path, Duration::from_millis(200))
+            .wait()
+            .unwrap();
+        // Less a millisecond, since the file only keeps whole milliseconds.
+        assert!(start.elapsed() >= Duration::from_millis(199));
+
+        fs::remove_file(path).unwrap();
+    }
+}
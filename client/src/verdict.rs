@@ -0,0 +1,223 @@
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+/// What the site made of a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The answer wasn't checked, because the last one was too recent.
+    #[serde(skip)]
+    Wait(Duration),
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page the site replies to a submission
+    /// with.
+    pub fn from_reply(reply: &str) -> Result<Self> {
+        if reply.contains("That's the right answer") {
+            Ok(Verdict::Correct)
+        } else if reply.contains("your answer is too high") {
+            Ok(Verdict::TooHigh)
+        } else if reply.contains("your answer is too low") {
+            Ok(Verdict::TooLow)
+        } else if reply.contains("That's not the right answer") {
+            Ok(Verdict::Wrong)
+        } else if let Some(wait) = parse_wait(reply) {
+            Ok(Verdict::Wait(wait))
+        } else if reply.contains("Did you already complete it?") {
+            Err(anyhow!("that part is already solved, or isn't open yet"))
+        } else {
+            Err(anyhow!("couldn't find a verdict in the reply"))
+        }
+    }
+}
+
+/// Reads how long is left from e.g. "You have 1m 5s left to wait".
+fn parse_wait(reply: &str) -> Option<Duration> {
+    let (before, _) = reply.split_once(" left to wait")?;
+    let (_, left) = before.rsplit_once("You have ")?;
+
+    left.split_whitespace()
+        .try_fold(Duration::ZERO, |total, part| {
+            let (n, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let n: u64 = n.parse().ok()?;
+            match unit {
+                "h" => Some(total + Duration::from_secs(n * 3600)),
+                "m" => Some(total + Duration::from_secs(n * 60)),
+                "s" => Some(total + Duration::from_secs(n)),
+                _ => None,
+            }
+        })
+}
+
+/// Every verdict the site has given, keyed by day, then part, then answer:
+///
+/// ```toml
+/// [day1.part_one]
+/// 54390 = "too-high"
+/// 54388 = "correct"
+/// ```
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Verdicts(BTreeMap<String, BTreeMap<String, BTreeMap<String, Verdict>>>);
+
+impl Verdicts {
+    /// Reads the verdicts at `path`, or none if it doesn't exist yet.
+    pub fn read(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("couldn't read verdicts {}", path.display()))?;
+        toml::from_str(&contents)
+            .with_context(|| format!("couldn't parse verdicts {}", path.display()))
+    }
+
+    pub fn write(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("couldn't create {}", dir.display()))?;
+        }
+        fs::write(path, toml::to_string(self)?)
+            .with_context(|| format!("couldn't write verdicts {}", path.display()))
+    }
+
+    /// The verdict `answer` would get, if the verdicts so far already tell:
+    /// it was submitted before, another answer was correct, or it's beyond an
+    /// answer that was too high or too low.
+    pub fn known(&self, day: u8, level: u8, answer: &str) -> Option<Verdict> {
+        let verdicts = self.0.get(&day_key(day))?.get(part_key(level))?;
+        if let Some(&verdict) = verdicts.get(answer) {
+            return Some(verdict);
+        }
+        if verdicts
+            .values()
+            .any(|&verdict| verdict == Verdict::Correct)
+        {
+            return Some(Verdict::Wrong);
+        }
+
+        let answer: i128 = answer.parse().ok()?;
+        verdicts.iter().find_map(|(known, &verdict)| {
+            let known: i128 = known.parse().ok()?;
+            match verdict {
+                Verdict::TooHigh if answer >= known => Some(Verdict::TooHigh),
+                Verdict::TooLow if answer <= known => Some(Verdict::TooLow),
+                _ => None,
+            }
+        })
+    }
+
+    /// Remembers `verdict` for `answer`. Waits say nothing about the answer,
+    /// so they aren't kept.
+    pub fn record(&mut self, day: u8, level: u8, answer: &str, verdict: Verdict) {
+        if let Verdict::Wait(_) = verdict {
+            return;
+        }
+
+        self.0
+            .entry(day_key(day))
+            .or_default()
+            .entry(part_key(level).to_string())
+            .or_default()
+            .insert(answer.to_string(), verdict);
+    }
+}
+
+fn day_key(day: u8) -> String {
+    format!("day{}", day)
+}
+
+fn part_key(level: u8) -> &'static str {
+    match level {
+        1 => "part_one",
+        _ => "part_two",
+    }
+}
+
+#[cfg(test)]
+mod verdict_tests {
+    use super::*;
+
+    #[test]
+    fn reads_replies() {
+        let reply =
+            |text| Verdict::from_reply(&format!("<main><article><p>{}</p></article></main>", text));
+
+        assert_eq!(
+            reply("That's the right answer!  You are one gold star closer.").unwrap(),
+            Verdict::Correct
+        );
+        assert_eq!(
+            reply("That's not the right answer; your answer is too high.").unwrap(),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            reply("That's not the right answer; your answer is too low.").unwrap(),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            reply("That's not the right answer.  Please wait one minute before trying again.")
+                .unwrap(),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            reply("You gave an answer too recently.  You have 1m 5s left to wait.").unwrap(),
+            Verdict::Wait(Duration::from_secs(65))
+        );
+        assert_eq!(
+            reply("You have 35s left to wait.").unwrap(),
+            Verdict::Wait(Duration::from_secs(35))
+        );
+        assert!(reply(
+            "You don't seem to be solving the right level.  Did you already complete it?"
+        )
+        .is_err());
+        assert!(reply("Something else entirely").is_err());
+    }
+
+    #[test]
+    fn knows_verdicts_from_earlier_ones() {
+        let mut verdicts = Verdicts::default();
+        verdicts.record(1, 1, "54390", Verdict::TooHigh);
+        verdicts.record(1, 1, "100", Verdict::TooLow);
+        verdicts.record(1, 1, "200", Verdict::Wrong);
+        verdicts.record(1, 1, "300", Verdict::Wait(Duration::from_secs(5)));
+
+        assert_eq!(verdicts.known(1, 1, "200"), Some(Verdict::Wrong));
+        assert_eq!(verdicts.known(1, 1, "60000"), Some(Verdict::TooHigh));
+        assert_eq!(verdicts.known(1, 1, "54390"), Some(Verdict::TooHigh));
+        assert_eq!(verdicts.known(1, 1, "99"), Some(Verdict::TooLow));
+        assert_eq!(verdicts.known(1, 1, "300"), None);
+        assert_eq!(verdicts.known(1, 1, "abc"), None);
+        assert_eq!(verdicts.known(1, 2, "200"), None);
+
+        verdicts.record(1, 1, "54388", Verdict::Correct);
+        assert_eq!(verdicts.known(1, 1, "54388"), Some(Verdict::Correct));
+        assert_eq!(verdicts.known(1, 1, "300"), Some(Verdict::Wrong));
+    }
+
+    #[test]
+    fn round_trips() {
+        let mut verdicts = Verdicts::default();
+        verdicts.record(4, 2, "9721255", Verdict::Correct);
+        verdicts.record(4, 2, "5", Verdict::TooLow);
+
+        let written = toml::to_string(&verdicts).unwrap();
+        assert_eq!(
+            written,
+            "[day4.part_two]\n5 = \"too-low\"\n9721255 = \"correct\"\n"
+        );
+        let read: Verdicts = toml::from_str(&written).unwrap();
+        assert_eq!(read.known(4, 2, "5"), Some(Verdict::TooLow));
+    }
+}
@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::fmt;
+
+/// A puzzle answer, whichever type the day's solver happened to produce.
+///
+/// Numeric answers compare equal regardless of how they were stored, so
+/// `Answer::from(5u32) == Answer::from(5isize)`. Anything else compares by its
+/// printed form.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Big(i128),
+    Text(String),
+}
+
+impl Answer {
+    fn as_i128(&self) -> Option<i128> {
+        match self {
+            Answer::Signed(n) => Some(*n as i128),
+            Answer::Unsigned(n) => Some(*n as i128),
+            Answer::Big(n) => Some(*n),
+            Answer::Text(_) => None,
+        }
+    }
+
+    fn printed(&self) -> Cow<'_, str> {
+        match self {
+            Answer::Text(s) => Cow::Borrowed(s),
+            _ => Cow::Owned(self.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{}", n),
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::Big(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.as_i128(), other.as_i128()) {
+            (Some(a), Some(b)) => a == b,
+            _ => self.printed() == other.printed(),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+macro_rules! impl_from_int {
+    ($variant:ident, $inner:ty, $($t:ty),+) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::$variant(n as $inner)
+                }
+            }
+
+            impl PartialEq<$t> for Answer {
+                fn eq(&self, other: &$t) -> bool {
+                    match self.as_i128() {
+                        Some(n) => n == *other as i128,
+                        None => self.printed() == other.to_string(),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_from_int!(Unsigned, u64, u8, u16, u32, u64, usize);
+impl_from_int!(Signed, i64, i8, i16, i32, i64, isize);
+impl_from_int!(Big, i128, i128);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self.printed() == *other
+    }
+}
+
+#[cfg(test)]
+mod answer_tests {
+    use super::*;
+
+    #[test]
+    fn numeric_answers_compare_across_types() {
+        assert_eq!(Answer::from(54667u32), Answer::from(54667usize));
+        assert_eq!(Answer::from(1005isize), 1005u64);
+        assert_eq!(Answer::from(16563603485021usize), 16563603485021i128);
+        assert_ne!(Answer::from(-1isize), Answer::from(u64::MAX));
+    }
+
+    #[test]
+    fn text_answers() {
+        assert_eq!(Answer::from("EZFKLJ"), "EZFKLJ");
+        assert_eq!(Answer::from(42u32), "42");
+        assert_eq!(Answer::from("42"), Answer::from(42u32));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Answer::from(-3isize).to_string(), "-3");
+        assert_eq!(Answer::from(i128::MAX).to_string(), i128::MAX.to_string());
+    }
+}
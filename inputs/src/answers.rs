@@ -0,0 +1,107 @@
+use crate::{checked_in_dir, Answer, InFile, Input, Part, Solution};
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+
+/// The answers a single input should give.
+#[derive(Debug, Default, Deserialize)]
+pub struct Expected {
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+}
+
+impl Expected {
+    pub fn get(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part_one.as_ref(),
+            Part::Two => self.part_two.as_ref(),
+        }
+    }
+}
+
+/// The registry of known answers in `answers.toml`, keyed by day and then by
+/// the name of a checked-in input file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, Expected>>);
+
+impl Answers {
+    pub fn read() -> Result<Self> {
+        let path = checked_in_dir().join("answers.toml");
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("couldn't read answers {}", path.display()))?;
+        toml::from_str(&contents)
+            .with_context(|| format!("couldn't parse answers {}", path.display()))
+    }
+
+    /// Every registered input for `day`, with its expected answers.
+    pub fn inputs(&self, day: u8) -> impl Iterator<Item = (&str, &Expected)> {
+        self.0
+            .get(&format!("day{}", day))
+            .into_iter()
+            .flatten()
+            .map(|(name, expected)| (name.as_str(), expected))
+    }
+
+    pub fn get(&self, day: u8, name: &str) -> Option<&Expected> {
+        self.0.get(&format!("day{}", day))?.get(name)
+    }
+
+    /// The expected answers for `input`, if it's one of the checked-in inputs.
+    pub fn for_input(&self, day: u8, input: &Input) -> Option<&Expected> {
+        let Input::File(path) = input else {
+            return None;
+        };
+
+        let path = path.canonicalize().ok()?;
+        if path.parent()? != checked_in_dir().canonicalize().ok()? {
+            return None;
+        }
+
+        self.get(day, path.file_name()?.to_str()?)
+    }
+}
+
+/// Solves `part` of every registered input for `S`, reporting each one that
+/// doesn't give its expected answer.
+pub fn check_answers<S: Solution>(part: Part) -> Result<()> {
+    let answers = Answers::read()?;
+    let mut failures = Vec::new();
+    let mut checked = 0;
+
+    for (name, expected) in answers.inputs(S::DAY) {
+        let Some(expected) = expected.get(part) else {
+            continue;
+        };
+        checked += 1;
+
+        let answer = Input::checked_in(name)
+            .read()
+            .and_then(|input| S::parse(&input))
+            .and_then(|parsed| S::solve(part, &parsed))
+            .in_file(name);
+        match answer {
+            Ok(answer) if &answer == expected => {}
+            Ok(answer) => failures.push(format!("{}: expected {}, got {}", name, expected, answer)),
+            Err(e) => failures.push(format!("{}: {:#}", name, e)),
+        }
+    }
+
+    if checked == 0 {
+        return Err(anyhow!("day {} has no answers for {}", S::DAY, part.name()));
+    }
+
+    if !failures.is_empty() {
+        return Err(anyhow!(
+            "day {} {} failed {} of {} inputs:\n{}",
+            S::DAY,
+            part.name(),
+            failures.len(),
+            checked,
+            failures.join("\n")
+        ));
+    }
+
+    Ok(())
+}
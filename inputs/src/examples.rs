@@ -0,0 +1,91 @@
+use crate::{checked_in_dir, Answer, InFile, Part, Solution};
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+use std::fs;
+
+/// A worked example from a puzzle statement, with the answers it should give.
+///
+/// Examples only need an answer for the parts they apply to, since some
+/// puzzles give part two its own example input.
+#[derive(Debug, Deserialize)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+}
+
+impl Example {
+    pub fn expected(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part_one.as_ref(),
+            Part::Two => self.part_two.as_ref(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Examples {
+    example: Vec<Example>,
+}
+
+/// Reads the examples for `day` from `examples/dayN.toml`.
+pub fn read_examples(day: u8) -> Result<Vec<Example>> {
+    let path = checked_in_dir()
+        .join("examples")
+        .join(format!("day{}.toml", day));
+    let contents = fs::read_to_string(&path)
+        .with_context(|| format!("couldn't read examples {}", path.display()))?;
+    let examples: Examples = toml::from_str(&contents)
+        .with_context(|| format!("couldn't parse examples {}", path.display()))?;
+
+    Ok(examples.example)
+}
+
+/// Solves `part` of every example for `S` that has an answer for it,
+/// reporting each example that doesn't match.
+pub fn check_examples<S: Solution>(part: Part) -> Result<()> {
+    let examples = read_examples(S::DAY)?;
+    let mut failures = Vec::new();
+    let mut checked = 0;
+
+    for example in &examples {
+        let Some(expected) = example.expected(part) else {
+            continue;
+        };
+        checked += 1;
+
+        let answer = S::parse(&example.input)
+            .and_then(|parsed| S::solve(part, &parsed))
+            .in_file(&example.name);
+        match answer {
+            Ok(answer) if &answer == expected => {}
+            Ok(answer) => failures.push(format!(
+                "{:?}: expected {}, got {}",
+                example.name, expected, answer
+            )),
+            Err(e) => failures.push(format!("{:?}: {:#}", example.name, e)),
+        }
+    }
+
+    if checked == 0 {
+        return Err(anyhow!(
+            "day {} has no examples for {}",
+            S::DAY,
+            part.name()
+        ));
+    }
+
+    if !failures.is_empty() {
+        return Err(anyhow!(
+            "day {} {} failed {} of {} examples:\n{}",
+            S::DAY,
+            part.name(),
+            failures.len(),
+            checked,
+            failures.join("\n")
+        ));
+    }
+
+    Ok(())
+}
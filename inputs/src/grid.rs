@@ -0,0 +1,300 @@
+use crate::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// The offsets of the 4 orthogonal neighbours of a cell, clockwise from above.
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The offsets of all 8 neighbours of a cell, clockwise from above.
+const SURROUNDING: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A rectangular grid of cells, addressed by `(x, y)` from the top left.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid of `width` columns made of `cells` in row order.
+    ///
+    /// # Panics
+    ///
+    /// If `cells` doesn't fill a whole number of rows.
+    pub fn new(width: usize, cells: Vec<T>) -> Self {
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "{} cells don't make rows of {}",
+            cells.len(),
+            width
+        );
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.contains(x, y).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// The positions of the cells above, right of, below and left of `(x, y)`
+    /// that are inside the grid.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offset_by(x, y, &ORTHOGONAL)
+    }
+
+    /// The positions of the cells surrounding `(x, y)`, diagonals included,
+    /// that are inside the grid.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offset_by(x, y, &SURROUNDING)
+    }
+
+    fn offset_by(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let x = x.checked_add_signed(dx)?;
+            let y = y.checked_add_signed(dy)?;
+            self.contains(x, y).then_some((x, y))
+        })
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    /// The cells of column `x`, top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// If `x` is outside the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside the grid", x);
+        (0..self.height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// The grid mirrored along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self {
+        let cells = self.columns().flatten().cloned().collect();
+        Grid::new(self.height, cells)
+    }
+
+    /// The grid turned a quarter turn clockwise.
+    pub fn rotate_clockwise(&self) -> Self {
+        let cells = (0..self.width)
+            .flat_map(|x| (0..self.height).rev().map(move |y| (x, y)))
+            .map(|(x, y)| self[(x, y)].clone())
+            .collect();
+        Grid::new(self.height, cells)
+    }
+
+    /// The grid turned a quarter turn anticlockwise.
+    pub fn rotate_anticlockwise(&self) -> Self {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|x| (0..self.height).map(move |y| (x, y)))
+            .map(|(x, y)| self[(x, y)].clone())
+            .collect();
+        Grid::new(self.height, cells)
+    }
+}
+
+impl Grid<char> {
+    /// A grid with a cell for each character of `lines`, which must all be
+    /// the same length.
+    pub fn from_lines<S: AsRef<str>>(lines: &[S]) -> Result<Self, ParseError> {
+        let width = lines
+            .first()
+            .map_or(0, |line| line.as_ref().chars().count());
+        let mut cells = Vec::with_capacity(width * lines.len());
+
+        for (y, line) in lines.iter().enumerate() {
+            let line = line.as_ref();
+            let before = cells.len();
+            cells.extend(line.chars());
+
+            let found = cells.len() - before;
+            if found != width {
+                let message = format!("expected a row of {} cells, found {}", width, found);
+                let e = match line.char_indices().nth(width) {
+                    Some((extra, _)) => ParseError::new(line, &line[extra..], message),
+                    None => ParseError::missing(line, message),
+                };
+                return Err(e.on_line(y + 1));
+            }
+        }
+
+        Ok(Grid::new(width, cells))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::from_lines(&s.lines().collect::<Vec<_>>())
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the grid", x, y))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the grid", x, y))
+    }
+}
+
+/// Prints each row on its own line, with its cells side by side.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod grid_tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        "abc\ndef".parse().unwrap()
+    }
+
+    #[test]
+    fn parses_lines() {
+        let grid = grid();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(2, 1), Some(&'f'));
+        assert_eq!(grid.get(3, 1), None);
+        assert_eq!(grid.get(0, 2), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+        assert_eq!("".parse::<Grid<char>>().unwrap().height(), 0);
+    }
+
+    #[test]
+    fn rejects_ragged_lines() {
+        let e = "abc\nabcd".parse::<Grid<char>>().unwrap_err();
+        assert_eq!((e.line(), e.column(), e.text()), (2, 4, "d"));
+
+        let e = "abc\nab".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(e.message(), "expected a row of 3 cells, found 2");
+    }
+
+    #[test]
+    fn finds_neighbours_inside_the_grid() {
+        let grid = grid();
+
+        assert_eq!(grid.neighbours4(0, 0).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(
+            grid.neighbours8(0, 0).collect::<Vec<_>>(),
+            [(1, 0), (1, 1), (0, 1)]
+        );
+        assert_eq!(grid.neighbours8(1, 0).count(), 5);
+        assert_eq!(grid.neighbours4(1, 1).count(), 3);
+    }
+
+    #[test]
+    fn iterates_rows_and_columns() {
+        let grid = grid();
+
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            [['a', 'b', 'c'], ['d', 'e', 'f']]
+        );
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, ["ad", "be", "cf"]);
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &'e')));
+    }
+
+    #[test]
+    fn transposes_and_rotates() {
+        let grid = grid();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_anticlockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_clockwise().rotate_anticlockwise(), grid);
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase()).to_string(), "ABC\nDEF");
+    }
+}
@@ -0,0 +1,117 @@
+use crate::checked_in_dir;
+use anyhow::{Context, Result};
+use std::convert::Infallible;
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Overrides the directory that each day's default input is read from.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where a puzzle input comes from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Input {
+    File(PathBuf),
+    Stdin,
+    Text(String),
+}
+
+impl Input {
+    /// The default input for `day`: `dayN.txt` in `$AOC_INPUT_DIR`, falling
+    /// back to the checked-in inputs.
+    pub fn day(day: u8) -> Self {
+        let name = format!("day{}.txt", day);
+        match env::var_os(INPUT_DIR_VAR) {
+            Some(dir) => Input::File(PathBuf::from(dir).join(name)),
+            None => Input::checked_in(&name),
+        }
+    }
+
+    /// The input file called `name` in the checked-in inputs.
+    pub fn checked_in(name: &str) -> Self {
+        Input::File(checked_in_dir().join(name))
+    }
+
+    /// The input named by the program's first argument, or the default for `day`.
+    pub fn from_args(day: u8) -> Self {
+        match env::args().nth(1) {
+            Some(arg) => arg.parse().unwrap_or_else(|e| match e {}),
+            None => Input::day(day),
+        }
+    }
+
+    /// How the input is referred to in error messages.
+    pub fn name(&self) -> String {
+        match self {
+            Input::File(path) => path.display().to_string(),
+            Input::Stdin => "<stdin>".to_string(),
+            Input::Text(_) => "<input>".to_string(),
+        }
+    }
+
+    /// The file the input is read from, unless it's stdin or text.
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            Input::File(path) => Some(path),
+            Input::Stdin | Input::Text(_) => None,
+        }
+    }
+
+    pub fn read(&self) -> Result<String> {
+        match self {
+            Input::File(path) => fs::read_to_string(path)
+                .with_context(|| format!("couldn't read input {}", path.display())),
+            Input::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .context("couldn't read input from stdin")?;
+                Ok(input)
+            }
+            Input::Text(text) => Ok(text.clone()),
+        }
+    }
+}
+
+/// Parses a command-line argument: `-` is stdin, anything else is a file path.
+impl FromStr for Input {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => Input::Stdin,
+            path => Input::File(PathBuf::from(path)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod input_tests {
+    use super::*;
+
+    #[test]
+    fn parses_args() {
+        assert_eq!("-".parse(), Ok(Input::Stdin));
+        assert_eq!(
+            "teammate/day1.txt".parse(),
+            Ok(Input::File(PathBuf::from("teammate/day1.txt")))
+        );
+    }
+
+    #[test]
+    fn reads_text() {
+        let input = Input::Text("1abc2\npqr3stu8vwx\n".to_string());
+        assert_eq!(input.read().unwrap(), "1abc2\npqr3stu8vwx\n");
+    }
+
+    #[test]
+    fn day_honours_input_dir() {
+        env::set_var(INPUT_DIR_VAR, "/tmp/teammate");
+        let input = Input::day(4);
+        env::remove_var(INPUT_DIR_VAR);
+
+        assert_eq!(input, Input::File(PathBuf::from("/tmp/teammate/day4.txt")));
+    }
+}
@@ -1,7 +1,23 @@
 use anyhow::Result;
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::{self, BufRead, BufReader};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+mod answer;
+mod answers;
+mod examples;
+mod grid;
+mod input;
+mod parse_error;
+mod solution;
+
+pub use answer::Answer;
+pub use answers::{check_answers, Answers, Expected};
+pub use examples::{check_examples, read_examples, Example};
+pub use grid::Grid;
+pub use input::{Input, INPUT_DIR_VAR};
+pub use parse_error::{parse_lines, InFile, ParseError};
+pub use solution::{print_report, run, solve_input, Part, Report, Solution, Solved};
 
 pub type Lines = Vec<String>;
 
@@ -9,13 +25,28 @@ pub fn read_lines<P>(filename: P) -> Result<Lines>
 where
     P: AsRef<Path>,
 {
-    let dir = env!("CARGO_MANIFEST_DIR");
-    let path = Path::new(dir);
-    let file_path = path.join(filename);
-
-    let file = File::open(file_path)?;
+    let file = File::open(input_path(filename))?;
     BufReader::new(file)
         .lines()
         .collect::<Result<Vec<String>, io::Error>>()
         .map_err(Into::into)
 }
+
+pub fn read_input<P>(filename: P) -> Result<String>
+where
+    P: AsRef<Path>,
+{
+    fs::read_to_string(input_path(filename)).map_err(Into::into)
+}
+
+fn input_path<P>(filename: P) -> PathBuf
+where
+    P: AsRef<Path>,
+{
+    checked_in_dir().join(filename)
+}
+
+/// The directory the checked-in inputs, examples and answers live in.
+pub(crate) fn checked_in_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
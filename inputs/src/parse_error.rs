@@ -0,0 +1,194 @@
+use anyhow::Result;
+use std::error::Error;
+use std::fmt;
+
+/// A parse failure pointing at the offending text in the input, rendered with
+/// a snippet of the line it's on:
+///
+/// ```text
+/// unknown color
+///  --> inputs/day2.txt:3:11
+///   |
+/// 3 | Game 3: 8 purple, 6 blue
+///   |           ^^^^^^
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    file: Option<String>,
+    line: usize,
+    source_line: String,
+    offset: usize,
+    len: usize,
+}
+
+impl ParseError {
+    /// An error at `text` within `line`. `text` should be a slice of `line`,
+    /// as handed out by `split` and friends, so that it can be located exactly.
+    ///
+    /// The error is on line 1 until it's moved with `on_line`.
+    pub fn new(line: &str, text: &str, message: impl Into<String>) -> Self {
+        let offset = offset_in(line, text)
+            .or_else(|| line.find(text))
+            .unwrap_or(line.len());
+
+        Self {
+            message: message.into(),
+            file: None,
+            line: 1,
+            source_line: line.to_string(),
+            offset,
+            len: text.len(),
+        }
+    }
+
+    /// An error for something missing from the end of `line`.
+    pub fn missing(line: &str, message: impl Into<String>) -> Self {
+        Self::new(line, &line[line.len()..], message)
+    }
+
+    /// Moves the error to 1-based line number `line`.
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    /// Re-anchors an error raised while parsing `inner` to `outer`, which
+    /// `inner` is a slice of.
+    pub fn within(mut self, inner: &str, outer: &str) -> Self {
+        if let Some(start) = offset_in(outer, inner) {
+            self.offset += start;
+            self.source_line = outer.to_string();
+        }
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The 1-based column, in characters, that the offending text starts at.
+    pub fn column(&self) -> usize {
+        self.source_line[..self.offset].chars().count() + 1
+    }
+
+    pub fn text(&self) -> &str {
+        &self.source_line[self.offset..self.offset + self.len]
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let line_no = self.line.to_string();
+        let gutter = " ".repeat(line_no.len());
+        let carets = "^".repeat(self.text().chars().count().max(1));
+
+        writeln!(f, "{}", self.message)?;
+        writeln!(
+            f,
+            "{}--> {}:{}:{}",
+            gutter,
+            self.file().unwrap_or("<input>"),
+            self.line,
+            self.column()
+        )?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", line_no, self.source_line)?;
+        write!(
+            f,
+            "{} | {}{}",
+            gutter,
+            " ".repeat(self.column() - 1),
+            carets
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Names the file behind a `ParseError`, like `anyhow::Context` for locations.
+pub trait InFile<T> {
+    fn in_file(self, file: &str) -> Result<T>;
+}
+
+impl<T> InFile<T> for Result<T> {
+    fn in_file(self, file: &str) -> Result<T> {
+        self.map_err(|mut e| {
+            if let Some(parse_error) = e.downcast_mut::<ParseError>() {
+                parse_error.file = Some(file.to_string());
+            }
+            e
+        })
+    }
+}
+
+/// Parses each line of `input` with `parse`, numbering any error by its line.
+pub fn parse_lines<T, F>(input: &str, parse: F) -> std::result::Result<Vec<T>, ParseError>
+where
+    F: Fn(&str) -> std::result::Result<T, ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|e| e.on_line(i + 1)))
+        .collect()
+}
+
+/// The byte offset of `inner` in `outer`, if it's a slice of it.
+fn offset_in(outer: &str, inner: &str) -> Option<usize> {
+    let outer_start = outer.as_ptr() as usize;
+    let inner_start = inner.as_ptr() as usize;
+
+    (inner_start >= outer_start && inner_start + inner.len() <= outer_start + outer.len())
+        .then(|| inner_start - outer_start)
+}
+
+#[cfg(test)]
+mod parse_error_tests {
+    use super::*;
+
+    #[test]
+    fn locates_slices() {
+        let line = "Game 3: 8 purple, 6 blue";
+        let color = line
+            .split_whitespace()
+            .nth(3)
+            .unwrap()
+            .trim_end_matches(',');
+        let e = ParseError::new(line, color, "unknown color").on_line(3);
+
+        assert_eq!(e.line(), 3);
+        assert_eq!(e.column(), 11);
+        assert_eq!(e.text(), "purple");
+    }
+
+    #[test]
+    fn rebases_errors_from_inner_parsers() {
+        let line = "Card 1: 41 x8 | 83 86";
+        let numbers = &line[8..13];
+        let e = ParseError::new(numbers, &numbers[3..], "invalid number").within(numbers, line);
+
+        assert_eq!(e.column(), 12);
+        assert_eq!(e.text(), "x8");
+    }
+
+    #[test]
+    fn renders_snippet() {
+        let e = ParseError::new("1 2 three", "three", "invalid number").on_line(12);
+        let result: Result<()> = Err(e.into());
+        let e = result.in_file("day9.txt").unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "invalid number\n  --> day9.txt:12:5\n   |\n12 | 1 2 three\n   |     ^^^^^"
+        );
+    }
+}
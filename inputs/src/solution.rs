@@ -0,0 +1,128 @@
+use crate::{Answer, Answers, Expected, InFile, Input};
+use anyhow::Result;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Part::One => "part one",
+            Part::Two => "part two",
+        }
+    }
+}
+
+/// A single day's puzzle, solvable by the `aoc` runner or the day's own binary.
+///
+/// The input is parsed once into `Parsed`, which then feeds both parts.
+pub trait Solution {
+    const DAY: u8;
+
+    type Parsed;
+
+    fn parse(input: &str) -> Result<Self::Parsed>;
+
+    fn part_one(parsed: &Self::Parsed) -> Result<Answer>;
+    fn part_two(parsed: &Self::Parsed) -> Result<Answer>;
+
+    fn solve(part: Part, parsed: &Self::Parsed) -> Result<Answer> {
+        match part {
+            Part::One => Self::part_one(parsed),
+            Part::Two => Self::part_two(parsed),
+        }
+    }
+}
+
+/// The answers for one day's input, along with how long each phase took.
+#[derive(Debug)]
+pub struct Report {
+    pub day: u8,
+    pub parse_time: Duration,
+    pub parts: Vec<Solved>,
+}
+
+#[derive(Debug)]
+pub struct Solved {
+    pub part: Part,
+    pub answer: Answer,
+    pub time: Duration,
+}
+
+impl Report {
+    pub fn get(&self, part: Part) -> Option<&Solved> {
+        self.parts.iter().find(|solved| solved.part == part)
+    }
+
+    pub fn total_time(&self) -> Duration {
+        self.parse_time
+            + self
+                .parts
+                .iter()
+                .map(|solved| solved.time)
+                .sum::<Duration>()
+    }
+}
+
+/// Reads and parses `input` once and solves each of `parts`, timing every
+/// phase but the read.
+pub fn solve_input<S: Solution>(input: &Input, parts: &[Part]) -> Result<Report> {
+    let text = input.read()?;
+    let name = input.name();
+
+    let start = Instant::now();
+    let parsed = S::parse(&text).in_file(&name)?;
+    let parse_time = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = S::solve(part, &parsed).in_file(&name)?;
+            Ok(Solved {
+                part,
+                answer,
+                time: start.elapsed(),
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(Report {
+        day: S::DAY,
+        parse_time,
+        parts,
+    })
+}
+
+/// Solves the day's input, taking it from the first command-line argument if
+/// given, and prints both parts. Every day's own binary is just this.
+pub fn run<S: Solution>() -> Result<()> {
+    let input = Input::from_args(S::DAY);
+    let answers = Answers::read()?;
+    let report = solve_input::<S>(&input, &Part::BOTH)?;
+    print_report(&report, answers.for_input(S::DAY, &input));
+
+    Ok(())
+}
+
+/// Prints each answer in `report` under a "Day N" banner, marking it against
+/// `expected` where that's known.
+pub fn print_report(report: &Report, expected: Option<&Expected>) {
+    let title = format!("Day {}", report.day);
+    println!("{}", title);
+    println!("{}", "-".repeat(title.len()));
+
+    for Solved { part, answer, .. } in &report.parts {
+        match expected.and_then(|e| e.get(*part)) {
+            Some(expected) if answer == expected => println!("{}: {} ✔", part.name(), answer),
+            Some(expected) => println!("{}: {} ✘ (expected {})", part.name(), answer, expected),
+            None => println!("{}: {}", part.name(), answer),
+        }
+    }
+}
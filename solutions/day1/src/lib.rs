@@ -0,0 +1,164 @@
+use anyhow::Result;
+use inputs::{Answer, Lines, ParseError, Solution};
+use std::sync::LazyLock;
+
+mod scanner;
+mod vocabulary;
+
+pub use scanner::{Digit, Scanner, Source};
+pub use vocabulary::{Locale, Vocabulary};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Parsed = Lines;
+
+    fn parse(input: &str) -> Result<Lines> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part_one(lines: &Lines) -> Result<Answer> {
+        part_one(lines).map(Answer::from)
+    }
+
+    fn part_two(lines: &Lines) -> Result<Answer> {
+        part_two(lines).map(Answer::from)
+    }
+}
+
+/// The scanners for both parts, built once since building their automatons
+/// takes far longer than scanning a short input.
+static DIGITS: LazyLock<Scanner> = LazyLock::new(Scanner::digits);
+static ENGLISH: LazyLock<Scanner> = LazyLock::new(|| {
+    Scanner::new(&Vocabulary::digits(Locale::English))
+        .expect("English digits make a valid automaton")
+});
+
+fn part_one(lines: &Lines) -> Result<u32> {
+    calibration_sum(lines, &DIGITS)
+}
+
+fn part_two(lines: &Lines) -> Result<u32> {
+    calibration_sum(lines, &ENGLISH)
+}
+
+/// What calibrating one line found.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Calibration {
+    /// The 1-based line number.
+    pub line: usize,
+    /// The first and last digits of the line, unless it has none.
+    pub digits: Option<(Digit, Digit)>,
+}
+
+impl Calibration {
+    /// The first digit and the last as a two-digit number. Number words give
+    /// their leading digit when first and their trailing digit when last.
+    pub fn value(&self) -> Option<u32> {
+        let (first, last) = self.digits?;
+        Some(first.leading() * 10 + last.trailing())
+    }
+}
+
+/// Calibrates each of `lines` with `scanner`.
+pub fn calibrate<'a>(
+    lines: &'a [String],
+    scanner: &'a Scanner,
+) -> impl Iterator<Item = Calibration> + 'a {
+    lines.iter().enumerate().map(|(i, line)| Calibration {
+        line: i + 1,
+        digits: scanner.first(line).zip(scanner.last(line)),
+    })
+}
+
+/// Sums the calibration value of each of `lines`, which must all have a
+/// digit.
+pub fn calibration_sum(lines: &[String], scanner: &Scanner) -> Result<u32> {
+    let mut sum: u32 = 0;
+
+    for calibration in calibrate(lines, scanner) {
+        let value = calibration.value().ok_or_else(|| {
+            ParseError::missing(&lines[calibration.line - 1], "no digit found")
+                .on_line(calibration.line)
+        })?;
+        sum += value;
+    }
+
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod day1_tests {
+    use super::*;
+    use inputs::{check_answers, check_examples, solve_input, Input, Part};
+
+    #[test]
+    fn part_1() {
+        check_answers::<Day1>(Part::One).unwrap();
+    }
+
+    #[test]
+    fn part_2() {
+        check_answers::<Day1>(Part::Two).unwrap();
+    }
+
+    #[test]
+    fn example_part_1() {
+        check_examples::<Day1>(Part::One).unwrap();
+    }
+
+    #[test]
+    fn example_part_2() {
+        check_examples::<Day1>(Part::Two).unwrap();
+    }
+
+    #[test]
+    fn calibrates_with_other_vocabularies() {
+        let lines = |text: &str| -> Lines { text.lines().map(String::from).collect() };
+
+        let german = Scanner::new(&Vocabulary::digits(Locale::German)).unwrap();
+        assert_eq!(
+            calibration_sum(&lines("xzweiundvier7\nachtneun"), &german).unwrap(),
+            27 + 89
+        );
+
+        let numbers = Scanner::new(&Vocabulary::numbers(Locale::English)).unwrap();
+        assert_eq!(
+            calibration_sum(&lines("twelve\nthirty7x"), &numbers).unwrap(),
+            12 + 37
+        );
+    }
+
+    #[test]
+    fn reports_each_line() {
+        let lines: Lines = ["a1b2", "xtwone", "nada"].map(String::from).to_vec();
+        let scanner = Scanner::new(&Vocabulary::digits(Locale::English)).unwrap();
+        let calibrations: Vec<Calibration> = calibrate(&lines, &scanner).collect();
+
+        let (first, last) = calibrations[1].digits.unwrap();
+        assert_eq!(
+            (first.value, first.offset, first.source),
+            (2, 1, Source::Word)
+        );
+        assert_eq!((last.value, last.offset, last.source), (1, 3, Source::Word));
+        assert_eq!(calibrations[0].value(), Some(12));
+        assert_eq!(calibrations[2].digits, None);
+
+        let e = calibration_sum(&lines, &Scanner::digits()).unwrap_err();
+        let e = e.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((e.message(), e.line()), ("no digit found", 2));
+    }
+
+    #[test]
+    fn names_the_file_when_solving_fails() {
+        let path = std::env::temp_dir().join(format!("day1-bad-{}.txt", std::process::id()));
+        std::fs::write(&path, "a1b\nnothing\n").unwrap();
+        let e = solve_input::<Day1>(&Input::File(path.clone()), &[Part::One]).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+
+        let e = e.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((e.file(), e.line()), (path.to_str(), 2));
+    }
+}
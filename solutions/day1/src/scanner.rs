@@ -0,0 +1,196 @@
+use crate::Vocabulary;
+use aho_corasick::{AhoCorasick, MatchKind};
+use anyhow::Result;
+use std::fmt;
+
+/// A digit found in a line, spelled out or not.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Digit {
+    /// What the digit stands for, which for a number word like "twelve" is
+    /// more than one digit.
+    pub value: u32,
+    /// The byte offset the digit starts at in the line.
+    pub offset: usize,
+    pub source: Source,
+}
+
+/// How a digit was written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Source {
+    Digit,
+    Word,
+}
+
+impl Digit {
+    /// The first digit of the value, as in the 1 of "twelve".
+    pub fn leading(&self) -> u32 {
+        let mut value = self.value;
+        while value >= 10 {
+            value /= 10;
+        }
+
+        value
+    }
+
+    /// The last digit of the value, as in the 2 of "twelve".
+    pub fn trailing(&self) -> u32 {
+        self.value % 10
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Digit => write!(f, "digit"),
+            Source::Word => write!(f, "word"),
+        }
+    }
+}
+
+/// Finds the first and last digits of a line, written as digits or as any of
+/// the words of a vocabulary, with automatons built once from those words.
+///
+/// Where words overlap, as in `twone`, the first digit is the word that
+/// starts first and the last digit the word that ends last, so `twone` is
+/// 2 then 1. Where one word starts another, the longer one wins.
+pub struct Scanner {
+    forwards: AhoCorasick,
+    backwards: AhoCorasick,
+    values: Vec<u32>,
+}
+
+impl Scanner {
+    /// A scanner for digits written as digits, and as the words of
+    /// `vocabulary`.
+    pub fn new(vocabulary: &Vocabulary) -> Result<Self> {
+        let patterns: Vec<(String, u32)> = (0..10)
+            .map(|digit| (digit.to_string(), digit))
+            .chain(
+                vocabulary
+                    .words()
+                    .map(|(word, value)| (word.to_string(), value)),
+            )
+            .collect();
+
+        let forwards = automaton(
+            patterns
+                .iter()
+                .map(|(pattern, _)| pattern.bytes().collect()),
+        )?;
+        let backwards = automaton(
+            patterns
+                .iter()
+                .map(|(pattern, _)| pattern.bytes().rev().collect()),
+        )?;
+        let values = patterns.into_iter().map(|(_, value)| value).collect();
+
+        Ok(Self {
+            forwards,
+            backwards,
+            values,
+        })
+    }
+
+    /// A scanner for digits written only as digits.
+    pub fn digits() -> Self {
+        Self::new(&Vocabulary::default()).expect("digits make a valid automaton")
+    }
+
+    pub fn first(&self, line: &str) -> Option<Digit> {
+        let found = self.forwards.find(line)?;
+
+        Some(self.digit(found.pattern().as_usize(), found.start()))
+    }
+
+    /// Scans `line` from the end with the words reversed, so it only reads as
+    /// far back as the last digit.
+    pub fn last(&self, line: &str) -> Option<Digit> {
+        let reversed: Vec<u8> = line.bytes().rev().collect();
+        let found = self.backwards.find(&reversed)?;
+
+        Some(self.digit(found.pattern().as_usize(), line.len() - found.end()))
+    }
+
+    /// The digit that pattern `pattern` stands for, found at `offset`. The
+    /// first ten patterns are the digits themselves.
+    fn digit(&self, pattern: usize, offset: usize) -> Digit {
+        Digit {
+            value: self.values[pattern],
+            offset,
+            source: if pattern < 10 {
+                Source::Digit
+            } else {
+                Source::Word
+            },
+        }
+    }
+}
+
+fn automaton(patterns: impl Iterator<Item = Vec<u8>>) -> Result<AhoCorasick> {
+    Ok(AhoCorasick::builder()
+        .match_kind(MatchKind::LeftmostLongest)
+        .build(patterns)?)
+}
+
+#[cfg(test)]
+mod scanner_tests {
+    use super::*;
+    use crate::Locale;
+
+    fn values(scanner: &Scanner, line: &str) -> Option<(u32, u32)> {
+        Some((scanner.first(line)?.value, scanner.last(line)?.value))
+    }
+
+    #[test]
+    fn finds_digits() {
+        let scanner = Scanner::digits();
+
+        assert_eq!(values(&scanner, "pqr3stu8vwx"), Some((3, 8)));
+        assert_eq!(values(&scanner, "treb7uchet"), Some((7, 7)));
+        assert_eq!(values(&scanner, "two"), None);
+        assert_eq!(
+            scanner.last("a1b2c"),
+            Some(Digit {
+                value: 2,
+                offset: 3,
+                source: Source::Digit,
+            })
+        );
+    }
+
+    #[test]
+    fn finds_overlapping_words() {
+        let scanner = Scanner::new(&Vocabulary::digits(Locale::English)).unwrap();
+
+        assert_eq!(values(&scanner, "twone"), Some((2, 1)));
+        assert_eq!(values(&scanner, "eightwo"), Some((8, 2)));
+        assert_eq!(values(&scanner, "xtwone3four"), Some((2, 4)));
+        assert_eq!(values(&scanner, "oneight"), Some((1, 8)));
+        assert_eq!(
+            scanner.first("abcone2"),
+            Some(Digit {
+                value: 1,
+                offset: 3,
+                source: Source::Word,
+            })
+        );
+        assert_eq!(
+            scanner.last("twone"),
+            Some(Digit {
+                value: 1,
+                offset: 2,
+                source: Source::Word,
+            })
+        );
+    }
+
+    #[test]
+    fn prefers_longer_words() {
+        let scanner = Scanner::new(&Vocabulary::numbers(Locale::English)).unwrap();
+
+        assert_eq!(values(&scanner, "seventeen"), Some((17, 17)));
+        assert_eq!(values(&scanner, "sevenx"), Some((7, 7)));
+        assert_eq!(scanner.first("seventeen").unwrap().leading(), 1);
+        assert_eq!(scanner.last("seventeen").unwrap().trailing(), 7);
+    }
+}
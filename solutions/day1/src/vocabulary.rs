@@ -0,0 +1,237 @@
+use anyhow::{anyhow, Context, Result};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// The languages with built-in digit words.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Locale {
+    English,
+    German,
+    French,
+    Spanish,
+}
+
+impl Locale {
+    /// The words for 0 to 9.
+    fn digits(&self) -> [&'static str; 10] {
+        match self {
+            Locale::English => [
+                "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+            ],
+            Locale::German => [
+                "null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+            ],
+            Locale::French => [
+                "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+            ],
+            Locale::Spanish => [
+                "cero", "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+            ],
+        }
+    }
+
+    /// The words for 10 to 19, then for the tens from 20 to 90.
+    fn numbers(&self) -> ([&'static str; 10], [&'static str; 8]) {
+        match self {
+            Locale::English => (
+                [
+                    "ten",
+                    "eleven",
+                    "twelve",
+                    "thirteen",
+                    "fourteen",
+                    "fifteen",
+                    "sixteen",
+                    "seventeen",
+                    "eighteen",
+                    "nineteen",
+                ],
+                [
+                    "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+                ],
+            ),
+            Locale::German => (
+                [
+                    "zehn",
+                    "elf",
+                    "zwölf",
+                    "dreizehn",
+                    "vierzehn",
+                    "fünfzehn",
+                    "sechzehn",
+                    "siebzehn",
+                    "achtzehn",
+                    "neunzehn",
+                ],
+                [
+                    "zwanzig", "dreißig", "vierzig", "fünfzig", "sechzig", "siebzig", "achtzig",
+                    "neunzig",
+                ],
+            ),
+            Locale::French => (
+                [
+                    "dix", "onze", "douze", "treize", "quatorze", "quinze", "seize", "dix-sept",
+                    "dix-huit", "dix-neuf",
+                ],
+                [
+                    "vingt",
+                    "trente",
+                    "quarante",
+                    "cinquante",
+                    "soixante",
+                    "soixante-dix",
+                    "quatre-vingts",
+                    "quatre-vingt-dix",
+                ],
+            ),
+            Locale::Spanish => (
+                [
+                    "diez",
+                    "once",
+                    "doce",
+                    "trece",
+                    "catorce",
+                    "quince",
+                    "dieciséis",
+                    "diecisiete",
+                    "dieciocho",
+                    "diecinueve",
+                ],
+                [
+                    "veinte",
+                    "treinta",
+                    "cuarenta",
+                    "cincuenta",
+                    "sesenta",
+                    "setenta",
+                    "ochenta",
+                    "noventa",
+                ],
+            ),
+        }
+    }
+}
+
+/// Parses a locale code: `en`, `de`, `fr` or `es`.
+impl FromStr for Locale {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "en" => Ok(Locale::English),
+            "de" => Ok(Locale::German),
+            "fr" => Ok(Locale::French),
+            "es" => Ok(Locale::Spanish),
+            _ => Err(anyhow!("unknown locale {:?}, expected en, de, fr or es", s)),
+        }
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let code = match self {
+            Locale::English => "en",
+            Locale::German => "de",
+            Locale::French => "fr",
+            Locale::Spanish => "es",
+        };
+        write!(f, "{}", code)
+    }
+}
+
+/// The words a calibration line may spell its digits with, and what they
+/// stand for.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+}
+
+impl Vocabulary {
+    pub fn new(words: impl IntoIterator<Item = (String, u32)>) -> Self {
+        Self {
+            words: words.into_iter().collect(),
+        }
+    }
+
+    /// The words for 0 to 9 in `locale`.
+    pub fn digits(locale: Locale) -> Self {
+        Self::new(
+            locale
+                .digits()
+                .into_iter()
+                .zip(0..)
+                .map(|(word, value)| (word.to_string(), value)),
+        )
+    }
+
+    /// The words for 0 to 19 and for the tens up to 90 in `locale`.
+    pub fn numbers(locale: Locale) -> Self {
+        let (teens, tens) = locale.numbers();
+        let mut vocabulary = Self::digits(locale);
+        vocabulary.words.extend(
+            teens
+                .into_iter()
+                .zip(10..)
+                .chain(tens.into_iter().zip((20..).step_by(10)))
+                .map(|(word, value)| (word.to_string(), value)),
+        );
+
+        vocabulary
+    }
+
+    /// Reads a vocabulary from a TOML file of `word = value` pairs.
+    pub fn read(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("couldn't read vocabulary {}", path.display()))?;
+        let words: BTreeMap<String, u32> = toml::from_str(&contents)
+            .with_context(|| format!("couldn't parse vocabulary {}", path.display()))?;
+
+        if words.contains_key("") {
+            return Err(anyhow!("vocabulary {} has an empty word", path.display()));
+        }
+
+        Ok(Self::new(words))
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.words
+            .iter()
+            .map(|(word, value)| (word.as_str(), *value))
+    }
+}
+
+#[cfg(test)]
+mod vocabulary_tests {
+    use super::*;
+
+    #[test]
+    fn builds_locales() {
+        let german = Vocabulary::digits("de".parse().unwrap());
+        assert_eq!(german.words().nth(5), Some(("fünf", 5)));
+        assert_eq!(german.words().count(), 10);
+
+        let english = Vocabulary::numbers(Locale::English);
+        assert_eq!(english.words().count(), 28);
+        assert!(english.words().any(|word| word == ("seventeen", 17)));
+        assert!(english.words().any(|word| word == ("ninety", 90)));
+
+        assert!("it".parse::<Locale>().is_err());
+    }
+
+    #[test]
+    fn reads_files() {
+        let path =
+            std::env::temp_dir().join(format!("day1-vocabulary-{}.toml", std::process::id()));
+        fs::write(&path, "uno = 1\n\"dieciséis\" = 16\n").unwrap();
+        let vocabulary = Vocabulary::read(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            vocabulary.words().collect::<Vec<_>>(),
+            [("dieciséis", 16), ("uno", 1)]
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use crate::Round;
+use anyhow::{anyhow, Context, Result};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// How many cubes of each colour the bag holds.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Bag {
+    cubes: BTreeMap<String, u32>,
+}
+
+impl Bag {
+    pub fn new<S: Into<String>>(cubes: impl IntoIterator<Item = (S, u32)>) -> Self {
+        Self {
+            cubes: cubes
+                .into_iter()
+                .map(|(colour, count)| (colour.into(), count))
+                .collect(),
+        }
+    }
+
+    /// The bag of the puzzle: 12 red, 13 green and 14 blue cubes.
+    pub fn standard() -> Self {
+        Self::new([("red", 12), ("green", 13), ("blue", 14)])
+    }
+
+    /// Reads a bag from a TOML file of `colour = count` pairs.
+    pub fn read(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("couldn't read bag {}", path.display()))?;
+        let cubes: BTreeMap<String, u32> = toml::from_str(&contents)
+            .with_context(|| format!("couldn't parse bag {}", path.display()))?;
+
+        if cubes.contains_key("") {
+            return Err(anyhow!("bag {} has an empty colour", path.display()));
+        }
+
+        Ok(Self { cubes })
+    }
+
+    /// The number of `colour` cubes, 0 for colours the bag doesn't have.
+    pub fn count(&self, colour: &str) -> u32 {
+        self.cubes.get(colour).copied().unwrap_or(0)
+    }
+
+    pub fn cubes(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.cubes
+            .iter()
+            .map(|(colour, count)| (colour.as_str(), *count))
+    }
+
+    /// Adds `count` cubes of `colour`.
+    pub fn add(&mut self, colour: &str, count: u32) {
+        *self.cubes.entry(colour.to_string()).or_insert(0) += count;
+    }
+
+    /// Sets the number of `colour` cubes to `count`.
+    pub fn set(&mut self, colour: &str, count: u32) {
+        self.cubes.insert(colour.to_string(), count);
+    }
+
+    /// Whether the bag has enough cubes of every colour to draw `round`.
+    pub fn holds(&self, round: &Round) -> bool {
+        round
+            .cubes()
+            .all(|(colour, count)| count <= self.count(colour))
+    }
+
+    /// Grows the bag until it holds `round`.
+    pub fn fit(&mut self, round: &Round) {
+        for (colour, count) in round.cubes() {
+            let held = self.cubes.entry(colour.to_string()).or_insert(0);
+            *held = (*held).max(count);
+        }
+    }
+}
+
+/// Parses a bag the way rounds are written, e.g. `12 red, 13 green, 14 blue`.
+impl FromStr for Bag {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let round: Round = s.parse()?;
+
+        Ok(Self::new(round.cubes()))
+    }
+}
+
+impl fmt::Display for Bag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (colour, count)) in self.cubes().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} {}", count, colour)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod bag_tests {
+    use super::*;
+
+    #[test]
+    fn holds_rounds() {
+        let bag: Bag = "2 red, 1 green".parse().unwrap();
+
+        assert!(bag.holds(&"2 red".parse().unwrap()));
+        assert!(!bag.holds(&"1 red, 2 green".parse().unwrap()));
+        assert!(!bag.holds(&"1 blue".parse().unwrap()));
+        assert_eq!(bag.to_string(), "1 green, 2 red");
+        assert!("2 red, green".parse::<Bag>().is_err());
+    }
+
+    #[test]
+    fn reads_files() {
+        let path = std::env::temp_dir().join(format!("day2-bag-{}.toml", std::process::id()));
+        fs::write(&path, "red = 12\nviolet = 3\n").unwrap();
+        let bag = Bag::read(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(bag, Bag::new([("red", 12), ("violet", 3)]));
+    }
+}
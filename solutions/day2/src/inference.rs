@@ -0,0 +1,292 @@
+use crate::{minimum_bag, Bag, Game, Round};
+use std::collections::BTreeMap;
+
+/// The natural log of the probability of drawing `round` from `bag` in one
+/// handful, without replacement, or negative infinity if the bag can't hold
+/// it. Each colour's cubes are told apart only by colour, so this is the
+/// multivariate hypergeometric probability of the round's counts.
+pub fn round_log_likelihood(round: &Round, bag: &Bag) -> f64 {
+    if !bag.holds(round) {
+        return f64::NEG_INFINITY;
+    }
+
+    let total: u32 = bag.cubes().map(|(_, count)| count).sum();
+    let drawn: u32 = round.cubes().map(|(_, count)| count).sum();
+    let ways: f64 = round
+        .cubes()
+        .map(|(colour, count)| ln_choose(bag.count(colour), count))
+        .sum();
+
+    ways - ln_choose(total, drawn)
+}
+
+/// The natural log of the probability of every round of `game`. The cubes go
+/// back in the bag between rounds, so the rounds are independent.
+pub fn log_likelihood(game: &Game, bag: &Bag) -> f64 {
+    game.rounds()
+        .iter()
+        .map(|round| round_log_likelihood(round, bag))
+        .sum()
+}
+
+/// The chance of a handful from `bag` the size of `round` being no more
+/// likely than `round`, which is 0 if the bag can't hold it. Every outcome
+/// of that size is weighed, so this is exact rather than approximated.
+pub fn round_p_value(round: &Round, bag: &Bag) -> f64 {
+    if !bag.holds(round) {
+        return 0.0;
+    }
+
+    let counts: Vec<u32> = bag.cubes().map(|(_, count)| count).collect();
+    let total = counts.iter().sum();
+    let drawn = round.cubes().map(|(_, count)| count).sum();
+    let observed = round_log_likelihood(round, bag);
+    let all = ln_choose(total, drawn);
+
+    let mut p = 0.0;
+    outcomes(&counts, drawn, 0.0, &mut |ways| {
+        // Allow for rounding, so that outcomes as likely as the round count.
+        if ways - all <= observed + 1e-9 {
+            p += (ways - all).exp();
+        }
+    });
+
+    p.min(1.0)
+}
+
+/// The chance of a game of as many rounds from `bag` having a round no more
+/// likely than the least likely round of `game`. The rounds are independent,
+/// so this is one minus the chance of every round being more likely.
+pub fn p_value(game: &Game, bag: &Bag) -> f64 {
+    let rounds = game.rounds();
+    if rounds.is_empty() {
+        return 1.0;
+    }
+
+    let least = rounds
+        .iter()
+        .map(|round| round_p_value(round, bag))
+        .fold(1.0, f64::min);
+
+    -(rounds.len() as f64 * (-least).ln_1p()).exp_m1()
+}
+
+/// Calls `visit` with the log of the number of ways to draw each split of
+/// `left` cubes between colours with `counts` cubes.
+fn outcomes(counts: &[u32], left: u32, ways: f64, visit: &mut dyn FnMut(f64)) {
+    match counts {
+        [] if left == 0 => visit(ways),
+        [] => {}
+        [count, rest @ ..] => {
+            for k in 0..=left.min(*count) {
+                outcomes(rest, left - k, ways + ln_choose(*count, k), visit);
+            }
+        }
+    }
+}
+
+/// The bag that most likely produced every game, and the log of how likely
+/// it made them, among bags of the colours seen with at most `max` cubes of
+/// each. `None` when some round draws more than `max` cubes of a colour.
+///
+/// The likelihood can keep growing with the size of the bag, so `max` bounds
+/// the search. The log-likelihood is a term for each colour's count less one
+/// for the bag's total, so the search finds the best split of every total,
+/// one colour at a time, and keeps the total that scores best. That covers
+/// every bag in bounds, so the maximum found is the global one.
+pub fn most_likely_bag(games: &[Game], max: u32) -> Option<(Bag, f64)> {
+    let mut bag = minimum_bag(games);
+    if bag.cubes().any(|(_, count)| count > max) {
+        return None;
+    }
+
+    let draws = Draws::new(games);
+    // best[total] is the highest sum of the colour terms of the colours so
+    // far over bags of `total` cubes, and counts[i][total] the count of the
+    // i-th colour in that bag.
+    let mut best = vec![0.0];
+    let mut counts: Vec<Vec<u32>> = Vec::new();
+
+    for (colour, least) in bag.cubes() {
+        let scores: Vec<(u32, f64)> = (least..=max)
+            .map(|count| (count, weighted_ln_choose(count, &draws.colours[colour])))
+            .collect();
+        let mut next = vec![f64::NEG_INFINITY; best.len() + max as usize];
+        let mut count_at = vec![0; next.len()];
+
+        for (total, &so_far) in best.iter().enumerate() {
+            if so_far == f64::NEG_INFINITY {
+                continue;
+            }
+            for &(count, score) in &scores {
+                let total = total + count as usize;
+                if so_far + score > next[total] {
+                    next[total] = so_far + score;
+                    count_at[total] = count;
+                }
+            }
+        }
+
+        best = next;
+        counts.push(count_at);
+    }
+
+    let (mut total, score) = best
+        .iter()
+        .enumerate()
+        .filter(|(_, &so_far)| so_far > f64::NEG_INFINITY)
+        .map(|(total, &so_far)| {
+            (
+                total,
+                so_far - weighted_ln_choose(total as u32, &draws.totals),
+            )
+        })
+        .max_by(|a, b| a.1.total_cmp(&b.1))?;
+
+    let colours: Vec<String> = bag.cubes().map(|(colour, _)| colour.to_string()).collect();
+    for (colour, count_at) in colours.iter().zip(&counts).rev() {
+        let count = count_at[total];
+        bag.set(colour, count);
+        total -= count as usize;
+    }
+
+    Some((bag, score))
+}
+
+/// How many rounds drew each number of cubes of each colour, and each number
+/// of cubes in all, which is all the likelihood of a bag depends on.
+struct Draws {
+    colours: BTreeMap<String, Vec<u32>>,
+    totals: Vec<u32>,
+}
+
+impl Draws {
+    fn new(games: &[Game]) -> Self {
+        let mut colours: BTreeMap<String, Vec<u32>> = BTreeMap::new();
+        let mut totals = Vec::new();
+
+        for round in games.iter().flat_map(Game::rounds) {
+            for (colour, count) in round.cubes() {
+                tally(colours.entry(colour.to_string()).or_default(), count);
+            }
+            tally(&mut totals, round.cubes().map(|(_, count)| count).sum());
+        }
+
+        Self { colours, totals }
+    }
+}
+
+fn tally(rounds: &mut Vec<u32>, count: u32) {
+    let count = count as usize;
+    if rounds.len() <= count {
+        rounds.resize(count + 1, 0);
+    }
+    rounds[count] += 1;
+}
+
+/// The sum of `ln_choose(n, k)` over `rounds[k]` rounds for each `k`.
+fn weighted_ln_choose(n: u32, rounds: &[u32]) -> f64 {
+    (0..)
+        .zip(rounds)
+        .filter(|&(_, &times)| times > 0)
+        .map(|(k, &times)| times as f64 * ln_choose(n, k))
+        .sum()
+}
+
+/// The natural log of `n` choose `k`, for `k <= n`.
+fn ln_choose(n: u32, k: u32) -> f64 {
+    let k = k.min(n - k);
+
+    (0..k)
+        .map(|i| ((n - i) as f64).ln() - ((i + 1) as f64).ln())
+        .sum()
+}
+
+#[cfg(test)]
+mod inference_tests {
+    use super::*;
+
+    fn game(s: &str) -> Game {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn scores_rounds() {
+        let bag = Bag::new([("red", 2), ("blue", 2)]);
+
+        // One of the six pairs is both reds, and four are one of each.
+        let p = round_log_likelihood(&"2 red".parse().unwrap(), &bag).exp();
+        assert!((p - 1.0 / 6.0).abs() < 1e-12);
+        let p = round_log_likelihood(&"1 red, 1 blue".parse().unwrap(), &bag).exp();
+        assert!((p - 4.0 / 6.0).abs() < 1e-12);
+        assert_eq!(
+            round_log_likelihood(&"3 red".parse().unwrap(), &bag),
+            f64::NEG_INFINITY
+        );
+
+        let p = log_likelihood(&game("Game 1: 2 red; 1 red, 1 blue"), &bag).exp();
+        assert!((p - 4.0 / 36.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn tests_rounds() {
+        let bag = Bag::new([("red", 2), ("blue", 2)]);
+
+        // Two blues are as likely as two reds, and one of each is likelier.
+        let p = round_p_value(&"2 red".parse().unwrap(), &bag);
+        assert!((p - 2.0 / 6.0).abs() < 1e-12);
+        let p = round_p_value(&"1 red, 1 blue".parse().unwrap(), &bag);
+        assert!((p - 1.0).abs() < 1e-12);
+        assert_eq!(round_p_value(&"3 red".parse().unwrap(), &bag), 0.0);
+
+        // Either of two rounds can be the unlikely one.
+        let p = p_value(&game("Game 1: 2 red; 1 red, 1 blue"), &bag);
+        assert!((p - (1.0 - 4.0 / 9.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn infers_bags() {
+        // Mostly red handfuls point to a mostly red bag.
+        let games = [
+            game("Game 1: 3 red; 3 red; 2 red, 1 blue"),
+            game("Game 2: 3 red; 1 red, 1 blue; 3 red"),
+        ];
+        let (bag, score) = most_likely_bag(&games, 20).unwrap();
+
+        assert!(bag.count("red") > 3 * bag.count("blue"));
+        let total = |bag| -> f64 { games.iter().map(|game| log_likelihood(game, bag)).sum() };
+        assert!((score - total(&bag)).abs() < 1e-9);
+        assert!(score > total(&minimum_bag(&games)));
+        assert!(games
+            .iter()
+            .all(|game| log_likelihood(game, &bag) > f64::NEG_INFINITY));
+
+        assert_eq!(most_likely_bag(&games, 2), None);
+    }
+
+    #[test]
+    fn infers_the_best_bag_in_bounds() {
+        let games = [
+            game("Game 1: 2 red, 1 green; 1 blue; 3 red"),
+            game("Game 2: 1 green, 1 blue; 2 blue; 1 red, 2 green"),
+        ];
+        let total = |bag: &Bag| -> f64 { games.iter().map(|game| log_likelihood(game, bag)).sum() };
+
+        for max in 3..=8 {
+            let mut best = f64::NEG_INFINITY;
+            for red in 3..=max {
+                for green in 2..=max {
+                    for blue in 2..=max {
+                        let bag = Bag::new([("red", red), ("green", green), ("blue", blue)]);
+                        best = best.max(total(&bag));
+                    }
+                }
+            }
+
+            let (bag, score) = most_likely_bag(&games, max).unwrap();
+            assert!((score - best).abs() < 1e-9);
+            assert!((total(&bag) - best).abs() < 1e-9);
+            assert!(bag.cubes().all(|(_, count)| count <= max));
+        }
+    }
+}
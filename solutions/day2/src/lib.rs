@@ -0,0 +1,289 @@
+use anyhow::Result;
+use inputs::{parse_lines, Answer, ParseError, Solution};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+mod bag;
+mod inference;
+mod stats;
+mod stream;
+
+pub use bag::Bag;
+pub use inference::{
+    log_likelihood, most_likely_bag, p_value, round_log_likelihood, round_p_value,
+};
+pub use stats::{ColourStats, Stats};
+pub use stream::{scan, totals, GameMax, Games, Totals};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Parsed = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Vec<Game>> {
+        Ok(parse_lines(input, Game::from_str)?)
+    }
+
+    fn part_one(games: &Vec<Game>) -> Result<Answer> {
+        part_one(games).map(Answer::from)
+    }
+
+    fn part_two(games: &Vec<Game>) -> Result<Answer> {
+        part_two(games).map(Answer::from)
+    }
+}
+
+fn part_one(games: &[Game]) -> Result<u64> {
+    Ok(possible_sum(games, &Bag::standard()))
+}
+
+fn part_two(games: &[Game]) -> Result<u64> {
+    let sum = games.iter().map(Game::power).sum();
+
+    Ok(sum)
+}
+
+/// Sums the ids of the games that `bag` makes possible.
+pub fn possible_sum(games: &[Game], bag: &Bag) -> u64 {
+    games
+        .iter()
+        .filter(|game| game.is_possible(bag))
+        .map(|game| game.id as u64)
+        .sum()
+}
+
+/// The smallest bag that makes every game possible.
+pub fn minimum_bag(games: &[Game]) -> Bag {
+    let mut bag = Bag::default();
+    games
+        .iter()
+        .flat_map(|game| &game.rounds)
+        .for_each(|round| bag.fit(round));
+
+    bag
+}
+
+/// The games that `bag` doesn't make possible, but would with one more cube
+/// of `colour`.
+pub fn possible_with_one_more<'a>(games: &'a [Game], bag: &Bag, colour: &str) -> Vec<&'a Game> {
+    let mut bigger = bag.clone();
+    bigger.add(colour, 1);
+
+    games
+        .iter()
+        .filter(|game| !game.is_possible(bag) && game.is_possible(&bigger))
+        .collect()
+}
+
+#[derive(Debug)]
+pub struct Game {
+    id: u32,
+    rounds: Vec<Round>,
+}
+
+impl Game {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn rounds(&self) -> &[Round] {
+        &self.rounds
+    }
+
+    /// Whether every round could have been drawn from `bag`.
+    pub fn is_possible(&self, bag: &Bag) -> bool {
+        self.rounds.iter().all(|round| bag.holds(round))
+    }
+
+    /// The smallest bag that makes this game possible.
+    pub fn min_bag(&self) -> Bag {
+        let mut bag = Bag::default();
+        self.rounds.iter().for_each(|round| bag.fit(round));
+
+        bag
+    }
+
+    /// The product of the fewest red, green and blue cubes the game needs,
+    /// which is 0 when it never draws one of them.
+    pub fn power(&self) -> u64 {
+        let bag = self.min_bag();
+        Bag::standard()
+            .cubes()
+            .map(|(colour, _)| bag.count(colour) as u64)
+            .product()
+    }
+
+    /// The cubes `bag` lacks to make this game possible.
+    pub fn shortfall(&self, bag: &Bag) -> Bag {
+        Bag::new(
+            self.min_bag()
+                .cubes()
+                .filter(|&(colour, count)| count > bag.count(colour))
+                .map(|(colour, count)| (colour, count - bag.count(colour))),
+        )
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut parts = s.split(": ");
+        let id_part = parts
+            .next()
+            .ok_or_else(|| ParseError::missing(s, "no id part"))?;
+        let rounds_part = parts
+            .next()
+            .ok_or_else(|| ParseError::missing(s, "no rounds part"))?;
+
+        let id_str = id_part.trim_start_matches("Game ");
+        let id = id_str
+            .parse()
+            .map_err(|_| ParseError::new(s, id_str, "invalid game id"))?;
+        let rounds = rounds_part.split(';').map(|s| s.trim()).try_fold(
+            Vec::new(),
+            |mut acc, round_str| {
+                acc.push(Round::from_str(round_str).map_err(|e| e.within(round_str, s))?);
+                Ok::<Vec<Round>, ParseError>(acc)
+            },
+        )?;
+
+        Ok(Game { id, rounds })
+    }
+}
+
+/// The cubes drawn in one round, by colour.
+#[derive(Debug)]
+pub struct Round {
+    cubes: BTreeMap<String, u32>,
+}
+
+impl Round {
+    /// The number of `colour` cubes drawn, 0 for colours that weren't.
+    pub fn count(&self, colour: &str) -> u32 {
+        self.cubes.get(colour).copied().unwrap_or(0)
+    }
+
+    pub fn cubes(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.cubes
+            .iter()
+            .map(|(colour, count)| (colour.as_str(), *count))
+    }
+}
+
+impl FromStr for Round {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut cubes: BTreeMap<String, u32> = BTreeMap::new();
+
+        for cube_str in s.split(',') {
+            let mut cube_parts = cube_str.split_whitespace();
+            let count_str = cube_parts
+                .next()
+                .ok_or_else(|| ParseError::new(s, cube_str, "no count part"))?;
+            let count: u32 = count_str
+                .parse()
+                .map_err(|_| ParseError::new(s, count_str, "invalid count"))?;
+
+            let color = cube_parts
+                .next()
+                .ok_or_else(|| ParseError::new(s, &cube_str[cube_str.len()..], "no color part"))?;
+            if !color.chars().all(char::is_alphabetic) {
+                return Err(ParseError::new(s, color, "invalid color"));
+            }
+
+            let total = cubes.entry(color.to_string()).or_insert(0);
+            *total = total
+                .checked_add(count)
+                .ok_or_else(|| ParseError::new(s, count_str, "count overflows"))?;
+        }
+
+        Ok(Self { cubes })
+    }
+}
+
+#[cfg(test)]
+mod day2_tests {
+    use super::*;
+    use inputs::{check_answers, check_examples, Part};
+
+    #[test]
+    fn part_1() {
+        check_answers::<Day2>(Part::One).unwrap();
+    }
+
+    #[test]
+    fn part_2() {
+        check_answers::<Day2>(Part::Two).unwrap();
+    }
+
+    #[test]
+    fn example_part_1() {
+        check_examples::<Day2>(Part::One).unwrap();
+    }
+
+    #[test]
+    fn example_part_2() {
+        check_examples::<Day2>(Part::Two).unwrap();
+    }
+
+    fn example() -> Vec<Game> {
+        let input = &inputs::read_examples(2).unwrap()[0].input;
+        Day2::parse(input).unwrap()
+    }
+
+    #[test]
+    fn takes_any_bag() {
+        let games = example();
+
+        assert_eq!(
+            possible_sum(&games, &"20 red, 13 green, 15 blue".parse().unwrap()),
+            15
+        );
+        assert_eq!(possible_sum(&games, &Bag::default()), 0);
+
+        let game: Game = "Game 7: 2 violet, 1 red; 3 violet".parse().unwrap();
+        assert_eq!(game.rounds()[1].count("violet"), 3);
+        assert!(game.is_possible(&"3 violet, 1 red".parse().unwrap()));
+        assert!(!game.is_possible(&Bag::standard()));
+        assert!("Game 8: 2 red!".parse::<Game>().is_err());
+
+        let game: Game = "Game 9: 3 red, 2 green; 4 red, 1 violet".parse().unwrap();
+        assert_eq!(game.power(), 0);
+        assert_eq!(part_two(&[game]).unwrap(), 0);
+    }
+
+    #[test]
+    fn answers_what_ifs() {
+        let games = example();
+
+        assert_eq!(
+            minimum_bag(&games),
+            Bag::new([("red", 20), ("green", 13), ("blue", 15)])
+        );
+
+        let bag = Bag::new([("red", 20), ("green", 13), ("blue", 14)]);
+        let ids = |colour| {
+            possible_with_one_more(&games, &bag, colour)
+                .iter()
+                .map(|game| game.id())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids("blue"), [4]);
+        assert_eq!(ids("red"), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn rejects_overflowing_counts() {
+        let e = Day2::parse("Game 1: 4000000000 red, 4000000000 red").unwrap_err();
+        let e = e.downcast_ref::<ParseError>().unwrap();
+
+        assert_eq!(
+            (e.message(), e.column(), e.text()),
+            ("count overflows", 25, "4000000000")
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{p_value, Bag, Game};
+use std::collections::BTreeMap;
+
+/// A summary of a record of games, as drawn from some bag.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    pub games: usize,
+    pub colours: Vec<ColourStats>,
+    /// How many games had each number of rounds.
+    pub rounds: BTreeMap<usize, usize>,
+    /// The games the bag doesn't make possible, with the cubes it lacks for
+    /// each.
+    pub impossible: Vec<(u32, Bag)>,
+    /// The games the bag makes possible but unlikely, with their `p_value`,
+    /// which is below the threshold the stats were taken at.
+    pub implausible: Vec<(u32, f64)>,
+}
+
+/// How many cubes of one colour were drawn per round.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColourStats {
+    pub colour: String,
+    /// The number of rounds the colour was drawn in.
+    pub rounds: usize,
+    pub max: u32,
+    /// The mean over every round, counting rounds without the colour as 0.
+    pub mean: f64,
+}
+
+impl Stats {
+    /// Summarises `games`, counting those with a `p_value` under `threshold`
+    /// as implausible.
+    pub fn new(games: &[Game], bag: &Bag, threshold: f64) -> Self {
+        let rounds = games.iter().flat_map(Game::rounds);
+        let round_count = rounds.clone().count();
+
+        let mut colours: BTreeMap<&str, (usize, u32, u32)> = BTreeMap::new();
+        for (colour, count) in rounds.flat_map(|round| round.cubes()) {
+            let (drawn, max, total) = colours.entry(colour).or_default();
+            *drawn += 1;
+            *max = (*max).max(count);
+            *total += count;
+        }
+
+        let mut rounds = BTreeMap::new();
+        for game in games {
+            *rounds.entry(game.rounds().len()).or_insert(0) += 1;
+        }
+
+        Self {
+            games: games.len(),
+            colours: colours
+                .into_iter()
+                .map(|(colour, (drawn, max, total))| ColourStats {
+                    colour: colour.to_string(),
+                    rounds: drawn,
+                    max,
+                    mean: total as f64 / round_count as f64,
+                })
+                .collect(),
+            rounds,
+            impossible: games
+                .iter()
+                .map(|game| (game.id(), game.shortfall(bag)))
+                .filter(|(_, shortfall)| *shortfall != Bag::default())
+                .collect(),
+            implausible: games
+                .iter()
+                .filter(|game| game.is_possible(bag))
+                .map(|game| (game.id(), p_value(game, bag)))
+                .filter(|&(_, p)| p < threshold)
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod stats_tests {
+    use super::*;
+    use crate::Day2;
+    use inputs::Solution;
+
+    #[test]
+    fn summarises_example() {
+        let input = &inputs::read_examples(2).unwrap()[0].input;
+        let stats = Stats::new(&Day2::parse(input).unwrap(), &Bag::standard(), 0.01);
+
+        assert_eq!(stats.games, 5);
+        assert_eq!(stats.rounds, BTreeMap::from([(2, 1), (3, 4)]));
+
+        let blue = &stats.colours[0];
+        assert_eq!(
+            (blue.colour.as_str(), blue.rounds, blue.max),
+            ("blue", 11, 15)
+        );
+        assert!((blue.mean - 50.0 / 14.0).abs() < 1e-9);
+
+        assert_eq!(
+            stats.impossible,
+            [
+                (3, Bag::new([("red", 8)])),
+                (4, Bag::new([("blue", 1), ("red", 2)]))
+            ]
+        );
+        assert!(stats.implausible.is_empty());
+    }
+
+    #[test]
+    fn flags_unlikely_games() {
+        let games =
+            Day2::parse("Game 1: 10 red; 10 red\nGame 2: 5 red, 5 blue\nGame 3: 11 red").unwrap();
+        let stats = Stats::new(&games, &"10 red, 10 blue".parse().unwrap(), 0.01);
+
+        assert_eq!(stats.impossible, [(3, Bag::new([("red", 1)]))]);
+        // Only two of the 184,756 handfuls of ten are all one colour.
+        let [(1, p)] = stats.implausible[..] else {
+            panic!("expected only game 1 to be implausible");
+        };
+        assert!((p - (1.0 - (1.0 - 2.0 / 184_756.0_f64).powi(2))).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,264 @@
+use inputs::ParseError;
+
+/// The answers to both parts, added up in one pass over the games.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Totals {
+    /// The sum of the ids of the games the standard bag makes possible.
+    pub possible: u64,
+    /// The sum of the powers of each game's smallest bag.
+    pub power: u64,
+}
+
+/// A game as the streaming parser sees it: its id, and the most cubes of each
+/// colour drawn in any one round.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GameMax {
+    pub id: u32,
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+impl GameMax {
+    /// Whether the standard bag of 12 red, 13 green and 14 blue cubes makes
+    /// the game possible.
+    pub fn is_possible(&self) -> bool {
+        self.red <= 12 && self.green <= 13 && self.blue <= 14
+    }
+
+    pub fn power(&self) -> u64 {
+        self.red as u64 * self.green as u64 * self.blue as u64
+    }
+}
+
+/// Parses the games in `input` one line at a time, straight from its bytes,
+/// without allocating unless a line fails to parse.
+///
+/// Unlike `Game`, only red, green and blue cubes are understood.
+pub fn scan(input: &[u8]) -> Games<'_> {
+    Games { input, line: 0 }
+}
+
+/// Adds up both parts in one pass over `input`.
+pub fn totals(input: &[u8]) -> Result<Totals, ParseError> {
+    scan(input).try_fold(Totals::default(), |mut totals, game| {
+        let game = game?;
+        if game.is_possible() {
+            totals.possible += game.id as u64;
+        }
+        totals.power += game.power();
+
+        Ok(totals)
+    })
+}
+
+pub struct Games<'a> {
+    input: &'a [u8],
+    line: usize,
+}
+
+impl Iterator for Games<'_> {
+    type Item = Result<GameMax, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.input.is_empty() {
+            return None;
+        }
+
+        let (line, rest) = match self.input.iter().position(|&b| b == b'\n') {
+            Some(end) => (&self.input[..end], &self.input[end + 1..]),
+            None => (self.input, &[][..]),
+        };
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        self.input = rest;
+        self.line += 1;
+
+        Some(parse_game(line).map_err(|failure| failure.at(line, self.line)))
+    }
+}
+
+/// Where a line failed to parse, kept as byte offsets until it's turned into
+/// a `ParseError` so that the happy path never allocates.
+struct Failure {
+    start: usize,
+    end: usize,
+    message: &'static str,
+}
+
+impl Failure {
+    fn new(start: usize, end: usize, message: &'static str) -> Self {
+        Self {
+            start,
+            end,
+            message,
+        }
+    }
+
+    fn at(self, line: &[u8], number: usize) -> ParseError {
+        let line = String::from_utf8_lossy(line);
+        let text = line.get(self.start..self.end).unwrap_or("");
+
+        ParseError::new(&line, text, self.message).on_line(number)
+    }
+}
+
+fn parse_game(line: &[u8]) -> Result<GameMax, Failure> {
+    let colon = line
+        .iter()
+        .position(|&b| b == b':')
+        .ok_or_else(|| Failure::new(line.len(), line.len(), "no rounds part"))?;
+    let id_start = if line.starts_with(b"Game ") { 5 } else { 0 };
+    let id = number(&line[id_start..colon])
+        .ok_or_else(|| Failure::new(id_start, colon, "invalid game id"))?;
+
+    let mut game = GameMax {
+        id,
+        ..GameMax::default()
+    };
+    let mut round = [0u32; 3];
+    let mut at = colon + 1;
+
+    loop {
+        at = skip_spaces(line, at);
+        let count_start = at;
+        at = skip_while(line, at, u8::is_ascii_digit);
+        let count_end = skip_while(line, at, |b| !b.is_ascii_whitespace());
+        let count = number(&line[count_start..at])
+            .filter(|_| at == count_end)
+            .ok_or_else(|| Failure::new(count_start, count_end, "invalid count"))?;
+
+        at = skip_spaces(line, at);
+        let start = at;
+        at = skip_while(line, at, u8::is_ascii_alphabetic);
+        let colour = match &line[start..at] {
+            b"red" => 0,
+            b"green" => 1,
+            b"blue" => 2,
+            b"" => return Err(Failure::new(start, start, "no color part")),
+            _ => return Err(Failure::new(start, at, "unknown color")),
+        };
+        round[colour] = round[colour]
+            .checked_add(count)
+            .ok_or_else(|| Failure::new(count_start, count_end, "count overflows"))?;
+
+        at = skip_spaces(line, at);
+        match line.get(at) {
+            Some(b',') => {}
+            Some(b';') | None => {
+                game.red = game.red.max(round[0]);
+                game.green = game.green.max(round[1]);
+                game.blue = game.blue.max(round[2]);
+                round = [0; 3];
+            }
+            Some(_) => return Err(Failure::new(at, at + 1, "expected , or ;")),
+        }
+
+        if at == line.len() {
+            return Ok(game);
+        }
+        at += 1;
+    }
+}
+
+/// Parses a run of ASCII digits, which mustn't be empty or overflow.
+fn number(digits: &[u8]) -> Option<u32> {
+    if digits.is_empty() {
+        return None;
+    }
+
+    digits.iter().try_fold(0u32, |n, &b| {
+        if !b.is_ascii_digit() {
+            return None;
+        }
+        n.checked_mul(10)?.checked_add((b - b'0') as u32)
+    })
+}
+
+fn skip_spaces(line: &[u8], at: usize) -> usize {
+    skip_while(line, at, |&b| b == b' ')
+}
+
+fn skip_while(line: &[u8], mut at: usize, skip: impl Fn(&u8) -> bool) -> usize {
+    while line.get(at).is_some_and(&skip) {
+        at += 1;
+    }
+
+    at
+}
+
+#[cfg(test)]
+mod stream_tests {
+    use super::*;
+    use crate::{part_one, part_two, Day2};
+    use inputs::{read_examples, Input, Solution};
+
+    #[test]
+    fn agrees_with_game() {
+        let mut inputs = vec![Input::checked_in("day2.txt").read().unwrap()];
+        inputs.extend(read_examples(2).unwrap().into_iter().map(|e| e.input));
+        // Games that never draw a colour have no power.
+        inputs.push("Game 1: 3 red, 2 green\nGame 2: 1 blue; 2 red, 3 green\n".to_string());
+
+        for input in inputs {
+            let games = Day2::parse(&input).unwrap();
+            assert_eq!(
+                totals(input.as_bytes()).unwrap(),
+                Totals {
+                    possible: part_one(&games).unwrap(),
+                    power: part_two(&games).unwrap(),
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn reads_rounds() {
+        let input = b"Game 7: 1 red, 2 red; 3 blue\r\nGame 8: 2 green";
+        let games: Vec<GameMax> = scan(input).map(Result::unwrap).collect();
+
+        assert_eq!(
+            games,
+            [
+                GameMax {
+                    id: 7,
+                    red: 3,
+                    green: 0,
+                    blue: 3
+                },
+                GameMax {
+                    id: 8,
+                    red: 0,
+                    green: 2,
+                    blue: 0
+                },
+            ]
+        );
+        assert_eq!(totals(b"").unwrap(), Totals::default());
+    }
+
+    #[test]
+    fn locates_errors() {
+        let error = |input: &[u8]| totals(input).unwrap_err();
+
+        let e = error(b"Game 1: 1 red\nGame 2: 3 purple, 1 red");
+        assert_eq!(
+            (e.message(), e.line(), e.text()),
+            ("unknown color", 2, "purple")
+        );
+
+        let e = error(b"Game x: 1 red");
+        assert_eq!((e.message(), e.text()), ("invalid game id", "x"));
+
+        let e = error(b"Game 1: 1x red");
+        assert_eq!((e.message(), e.text()), ("invalid count", "1x"));
+
+        let e = error(b"Game 1: 1 red, 2");
+        assert_eq!(e.message(), "no color part");
+
+        let e = error(b"Game 1 1 red");
+        assert_eq!(e.message(), "no rounds part");
+
+        let e = error(b"Game 1: 4000000000 red, 4000000000 red");
+        assert_eq!((e.message(), e.column()), ("count overflows", 25));
+    }
+}
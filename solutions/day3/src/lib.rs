@@ -0,0 +1,433 @@
+use anyhow::Result;
+use inputs::{Answer, Grid, ParseError, Solution};
+use std::iter;
+use std::ops::Range;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Parsed = Schematic;
+
+    fn parse(input: &str) -> Result<Schematic> {
+        Ok(Schematic::from_grid(input.parse()?)?)
+    }
+
+    fn part_one(schematic: &Schematic) -> Result<Answer> {
+        part_one(schematic).map(Answer::from)
+    }
+
+    fn part_two(schematic: &Schematic) -> Result<Answer> {
+        part_two(schematic).map(Answer::from)
+    }
+}
+
+fn part_one(schematic: &Schematic) -> Result<u64> {
+    let sum = schematic
+        .numbers_adjacent_to(|_| true)
+        .map(|n| u64::from(n.value))
+        .sum();
+
+    Ok(sum)
+}
+
+fn part_two(schematic: &Schematic) -> Result<u64> {
+    let sum = schematic
+        .symbols_with_numbers(2)
+        .filter(|(s, _)| s.kind == '*')
+        .map(|(_, numbers)| numbers.iter().map(|n| u64::from(n.value)).product::<u64>())
+        .sum();
+
+    Ok(sum)
+}
+
+#[derive(Debug)]
+pub struct Schematic {
+    cells: Grid<Cell>,
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+}
+
+/// What's at a position in the schematic, so that what surrounds anything can
+/// be looked up directly rather than searched for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    /// A digit of the number at this index in `Schematic::numbers`.
+    Number(u32),
+    /// The symbol at this index in `Schematic::symbols`.
+    Symbol(u32),
+}
+
+/// A part number, spanning columns `start_x..=end_x` of row `y`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Number {
+    pub value: u32,
+    pub start_x: usize,
+    pub end_x: usize,
+    pub y: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Symbol {
+    pub kind: char,
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Schematic {
+    fn from_grid(grid: Grid<char>) -> Result<Self, ParseError> {
+        let mut cells = grid.map(|_| Cell::Empty);
+        let mut numbers: Vec<Number> = Vec::new();
+        let mut symbols: Vec<Symbol> = Vec::new();
+
+        for (y, row) in grid.rows().enumerate() {
+            for token in tokens(row) {
+                match token {
+                    Token::Symbol(x) => {
+                        cells[(x, y)] = Cell::Symbol(symbols.len() as u32);
+                        symbols.push(Symbol { kind: row[x], x, y });
+                    }
+                    Token::Number(columns) => {
+                        let digits: String = row[columns.clone()].iter().collect();
+                        let value = digits
+                            .parse()
+                            .map_err(|_| invalid_number(row, columns.clone()).on_line(y + 1))?;
+
+                        for x in columns.clone() {
+                            cells[(x, y)] = Cell::Number(numbers.len() as u32);
+                        }
+                        numbers.push(Number {
+                            value,
+                            start_x: columns.start,
+                            end_x: columns.end - 1,
+                            y,
+                        });
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            cells,
+            numbers,
+            symbols,
+        })
+    }
+
+    pub fn numbers(&self) -> &[Number] {
+        &self.numbers
+    }
+
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    /// The numbers with a digit next to `symbol`, each only once.
+    pub fn numbers_around(&self, symbol: &Symbol) -> Vec<&Number> {
+        self.ids_around(
+            self.cells.neighbours8(symbol.x, symbol.y),
+            |cell| match cell {
+                Cell::Number(id) => Some(id),
+                _ => None,
+            },
+        )
+        .into_iter()
+        .map(|id| &self.numbers[id as usize])
+        .collect()
+    }
+
+    /// The symbols next to any digit of `number`, each only once.
+    pub fn symbols_around(&self, number: &Number) -> Vec<&Symbol> {
+        self.ids_around(self.positions_around(number), |cell| match cell {
+            Cell::Symbol(id) => Some(id),
+            _ => None,
+        })
+        .into_iter()
+        .map(|id| &self.symbols[id as usize])
+        .collect()
+    }
+
+    /// The numbers next to a symbol whose kind satisfies `kind`.
+    pub fn numbers_adjacent_to(
+        &self,
+        kind: impl Fn(char) -> bool,
+    ) -> impl Iterator<Item = &Number> {
+        self.numbers.iter().filter(move |n| {
+            self.positions_around(n)
+                .any(|position| match self.cells[position] {
+                    Cell::Symbol(id) => kind(self.symbols[id as usize].kind),
+                    _ => false,
+                })
+        })
+    }
+
+    /// The symbols with exactly `count` numbers around them, along with those
+    /// numbers.
+    pub fn symbols_with_numbers(
+        &self,
+        count: usize,
+    ) -> impl Iterator<Item = (&Symbol, Vec<&Number>)> {
+        self.symbols
+            .iter()
+            .map(|s| (s, self.numbers_around(s)))
+            .filter(move |(_, numbers)| numbers.len() == count)
+    }
+
+    /// Groups the numbers that are linked to each other through the symbols
+    /// they share, in the order their first numbers appear. Numbers that share
+    /// no symbol are a group of their own.
+    pub fn connected_numbers(&self) -> Vec<Vec<&Number>> {
+        let mut parents: Vec<usize> = (0..self.numbers.len()).collect();
+        for symbol in &self.symbols {
+            let around = self.numbers_around(symbol);
+            for pair in around.windows(2) {
+                let a = root(&mut parents, self.index_of(pair[0]));
+                let b = root(&mut parents, self.index_of(pair[1]));
+                parents[a.max(b)] = a.min(b);
+            }
+        }
+
+        let mut groups: Vec<Vec<&Number>> = Vec::new();
+        let mut group_of_root = vec![usize::MAX; self.numbers.len()];
+        for (id, number) in self.numbers.iter().enumerate() {
+            let root = root(&mut parents, id);
+            if group_of_root[root] == usize::MAX {
+                group_of_root[root] = groups.len();
+                groups.push(Vec::new());
+            }
+            groups[group_of_root[root]].push(number);
+        }
+
+        groups
+    }
+
+    /// The positions next to any digit of `number`, some more than once.
+    fn positions_around<'a>(
+        &'a self,
+        number: &'a Number,
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        (number.start_x..=number.end_x).flat_map(move |x| self.cells.neighbours8(x, number.y))
+    }
+
+    fn index_of(&self, number: &Number) -> usize {
+        match self.cells[(number.start_x, number.y)] {
+            Cell::Number(id) => id as usize,
+            _ => unreachable!("a number's cells point back to it"),
+        }
+    }
+
+    /// The ids that `pick` finds in the cells at `positions`, each only once.
+    fn ids_around(
+        &self,
+        positions: impl Iterator<Item = (usize, usize)>,
+        pick: impl Fn(Cell) -> Option<u32>,
+    ) -> Vec<u32> {
+        let mut ids: Vec<u32> = positions
+            .filter_map(|position| pick(self.cells[position]))
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+
+        ids
+    }
+}
+
+/// The root of `id`'s group in the union-find forest `parents`.
+fn root(parents: &mut [usize], mut id: usize) -> usize {
+    while parents[id] != id {
+        parents[id] = parents[parents[id]];
+        id = parents[id];
+    }
+
+    id
+}
+
+/// A run of cells in a row: all the columns of a number, or a single symbol.
+#[derive(Debug, PartialEq, Eq)]
+enum Token {
+    Number(Range<usize>),
+    Symbol(usize),
+}
+
+/// Splits `row` into its numbers and symbols, left to right.
+fn tokens(row: &[char]) -> impl Iterator<Item = Token> + '_ {
+    let mut x = 0;
+
+    iter::from_fn(move || {
+        while x < row.len() {
+            let start = x;
+            x += 1;
+
+            if row[start].is_ascii_digit() {
+                while x < row.len() && row[x].is_ascii_digit() {
+                    x += 1;
+                }
+                return Some(Token::Number(start..x));
+            }
+            if is_symbol(row[start]) {
+                return Some(Token::Symbol(start));
+            }
+        }
+
+        None
+    })
+}
+
+fn is_symbol(c: char) -> bool {
+    !c.is_ascii_digit() && c != '.'
+}
+
+/// An error at the number in `columns` of `row`.
+fn invalid_number(row: &[char], columns: Range<usize>) -> ParseError {
+    let line: String = row.iter().collect();
+    let offset = |x: usize| row[..x].iter().map(|c| c.len_utf8()).sum::<usize>();
+    let text = &line[offset(columns.start)..offset(columns.end)];
+
+    ParseError::new(&line, text, "invalid part number")
+}
+
+#[cfg(test)]
+mod day3_tests {
+    use super::*;
+    use inputs::{check_answers, check_examples, read_examples, Part};
+
+    #[test]
+    fn part_1() {
+        check_answers::<Day3>(Part::One).unwrap();
+    }
+
+    #[test]
+    fn part_2() {
+        check_answers::<Day3>(Part::Two).unwrap();
+    }
+
+    #[test]
+    fn example_part_1() {
+        check_examples::<Day3>(Part::One).unwrap();
+    }
+
+    #[test]
+    fn example_part_2() {
+        check_examples::<Day3>(Part::Two).unwrap();
+    }
+
+    fn chars(row: &str) -> Vec<char> {
+        row.chars().collect()
+    }
+
+    #[test]
+    fn tokenizes_rows() {
+        assert_eq!(
+            tokens(&chars("467..114..")).collect::<Vec<_>>(),
+            [Token::Number(0..3), Token::Number(5..8)]
+        );
+        assert_eq!(
+            tokens(&chars("617*.58")).collect::<Vec<_>>(),
+            [Token::Number(0..3), Token::Symbol(3), Token::Number(5..7)]
+        );
+        assert_eq!(tokens(&chars("....")).count(), 0);
+    }
+
+    #[test]
+    fn numbers_on_the_edge_of_a_line() {
+        let sum = |input: &str| part_one(&Day3::parse(input).unwrap()).unwrap();
+
+        assert_eq!(sum("...#12\n......"), 12);
+        assert_eq!(sum("..#.12\n......"), 0);
+        assert_eq!(sum("12.#..\n......"), 0);
+        assert_eq!(sum("......\n....12\n...#.."), 12);
+    }
+
+    #[test]
+    fn numbers_wider_than_a_gear() {
+        let schematic = Day3::parse("12345\n..*..\n..7..").unwrap();
+
+        assert_eq!(part_two(&schematic).unwrap(), 12345 * 7);
+    }
+
+    #[test]
+    fn non_ascii_symbols() {
+        let sum = |input: &str| part_one(&Day3::parse(input).unwrap()).unwrap();
+
+        assert_eq!(sum("é12\n..."), 12);
+        assert_eq!(sum("€€.12\n....."), 0);
+        assert_eq!(sum("€€.12\n..→.."), 12);
+        // Digits outside ASCII are symbols, not part of a number.
+        assert_eq!(sum("²12\n..."), 12);
+        assert_eq!(sum("12½\n..."), 12);
+        assert_eq!(sum("1½2\n..."), 3);
+    }
+
+    fn values(numbers: &[&Number]) -> Vec<u32> {
+        numbers.iter().map(|n| n.value).collect()
+    }
+
+    fn example() -> Schematic {
+        Day3::parse(&read_examples(3).unwrap()[0].input).unwrap()
+    }
+
+    #[test]
+    fn numbers_adjacent_to_a_kind() {
+        let schematic = example();
+        let adjacent_to = |kind: char| -> Vec<u32> {
+            schematic
+                .numbers_adjacent_to(|c| c == kind)
+                .map(|n| n.value)
+                .collect()
+        };
+
+        assert_eq!(adjacent_to('*'), [467, 35, 617, 755, 598]);
+        assert_eq!(adjacent_to('#'), [633]);
+        assert_eq!(adjacent_to('$'), [664]);
+        assert_eq!(adjacent_to('+'), [592]);
+        assert_eq!(adjacent_to('%'), []);
+    }
+
+    #[test]
+    fn symbols_with_numbers() {
+        let schematic = example();
+        let pairs: Vec<(char, Vec<u32>)> = schematic
+            .symbols_with_numbers(2)
+            .map(|(s, numbers)| (s.kind, values(&numbers)))
+            .collect();
+
+        assert_eq!(pairs, [('*', vec![467, 35]), ('*', vec![755, 598])]);
+        assert_eq!(schematic.symbols_with_numbers(1).count(), 4);
+        assert_eq!(schematic.symbols_with_numbers(3).count(), 0);
+    }
+
+    #[test]
+    fn connected_numbers() {
+        let schematic = Day3::parse("1.2.3\n.*.#.\n4...5\n.....\n6*7.8").unwrap();
+        let groups: Vec<Vec<u32>> = schematic
+            .connected_numbers()
+            .iter()
+            .map(|group| values(group))
+            .collect();
+
+        assert_eq!(groups, [vec![1, 2, 3, 4, 5], vec![6, 7], vec![8]]);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "slow in debug builds; runs with --release")]
+    fn stress_test() {
+        // The example's last column is empty and its first and last rows have
+        // no symbols, so nothing touches across tiles, and tiling it 500 times
+        // each way makes a 5000x5000 schematic with 250,000 times the answers.
+        let example = &read_examples(3).unwrap()[0];
+        let rows: Vec<String> = example.input.lines().map(|row| row.repeat(500)).collect();
+        let mut input = String::new();
+        for _ in 0..500 {
+            for row in &rows {
+                input.push_str(row);
+                input.push('\n');
+            }
+        }
+
+        let schematic = Day3::parse(&input).unwrap();
+        assert_eq!(part_one(&schematic).unwrap(), 4361 * 250_000);
+        assert_eq!(part_two(&schematic).unwrap(), 467835 * 250_000);
+    }
+}
@@ -0,0 +1,307 @@
+use anyhow::{anyhow, Result};
+use inputs::{parse_lines, Answer, ParseError, Solution};
+use std::str::FromStr;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Parsed = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Vec<Card>> {
+        let cards = parse_lines(input, Card::from_str)?;
+        check_ids(input, &cards)?;
+
+        Ok(cards)
+    }
+
+    fn part_one(cards: &Vec<Card>) -> Result<Answer> {
+        part_one(cards).map(Answer::from)
+    }
+
+    fn part_two(cards: &Vec<Card>) -> Result<Answer> {
+        part_two(cards).map(Answer::from)
+    }
+}
+
+fn part_one(cards: &[Card]) -> Result<u64> {
+    cards.iter().try_fold(0u64, |sum, card| {
+        card.score()
+            .and_then(|score| sum.checked_add(score))
+            .ok_or_else(|| anyhow!("card {} takes the points past {}", card.id, u64::MAX))
+    })
+}
+
+fn part_two(cards: &[Card]) -> Result<u64> {
+    cascade(cards)?
+        .iter()
+        .try_fold(0u64, |total, won| total.checked_add(won.copies))
+        .ok_or_else(|| anyhow!("more than {} cards in all", u64::MAX))
+}
+
+/// How many copies of a card there were once every card had won its copies,
+/// counting the original, and which cards won the others.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Copies {
+    pub id: u32,
+    pub copies: u64,
+    /// The ids of the cards that won copies of this one, with how many each.
+    pub won_from: Vec<(u32, u64)>,
+}
+
+/// Plays out part two: each copy of a card with `n` matches wins a copy of
+/// each of the next `n` cards. A card that would win copies of cards past the
+/// end of the table is an error.
+pub fn cascade(cards: &[Card]) -> Result<Vec<Copies>> {
+    let mut copies: Vec<Copies> = cards
+        .iter()
+        .map(|card| Copies {
+            id: card.id,
+            copies: 1,
+            won_from: Vec::new(),
+        })
+        .collect();
+
+    for (i, card) in cards.iter().enumerate() {
+        let num_matches = card.matches();
+        if i + num_matches >= cards.len() {
+            return Err(anyhow!(
+                "card {} wins copies of the next {} cards, but only {} follow it",
+                card.id,
+                num_matches,
+                cards.len() - i - 1
+            ));
+        }
+
+        let won = copies[i].copies;
+        for next in &mut copies[i + 1..=i + num_matches] {
+            next.copies = next
+                .copies
+                .checked_add(won)
+                .ok_or_else(|| anyhow!("more than {} copies of card {}", u64::MAX, next.id))?;
+            next.won_from.push((card.id, won));
+        }
+    }
+
+    Ok(copies)
+}
+
+#[derive(Clone, Debug)]
+pub struct Card {
+    id: u32,
+    numbers: Vec<u32>,
+    winning_numbers: Vec<u32>,
+}
+
+impl Card {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// How many of the card's numbers are winning numbers.
+    pub fn matches(&self) -> usize {
+        self.numbers
+            .iter()
+            .filter(|num| self.winning_numbers.contains(num))
+            .count()
+    }
+
+    /// The card's points, doubling with each match after the first, or `None`
+    /// when they don't fit in a `u64`.
+    fn score(&self) -> Option<u64> {
+        let num_matches = self.matches();
+        if num_matches == 0 {
+            Some(0)
+        } else {
+            u32::try_from(num_matches - 1)
+                .ok()
+                .and_then(|shift| 1u64.checked_shl(shift))
+        }
+    }
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut parts = s.split(": ");
+        let id_part = parts
+            .next()
+            .ok_or_else(|| ParseError::missing(s, "no id part"))?;
+        let numbers_part = parts
+            .next()
+            .ok_or_else(|| ParseError::missing(s, "no numbers part"))?;
+
+        let id_str = id_part.trim_start_matches("Card").trim_start();
+        let id = id_str
+            .parse()
+            .map_err(|_| ParseError::new(s, id_str, "invalid card id"))?;
+
+        let mut numbers_parts = numbers_part.split(" | ");
+
+        let numbers = parse_numbers(
+            s,
+            numbers_parts
+                .next()
+                .ok_or_else(|| ParseError::missing(s, "no numbers"))?,
+        )?;
+        let winning_numbers = parse_numbers(
+            s,
+            numbers_parts
+                .next()
+                .ok_or_else(|| ParseError::missing(s, "no winning numbers"))?,
+        )?;
+
+        Ok(Self {
+            id,
+            numbers,
+            winning_numbers,
+        })
+    }
+}
+
+/// Parses the numbers in `part` of line `s`, none of which may repeat.
+fn parse_numbers(s: &str, part: &str) -> std::result::Result<Vec<u32>, ParseError> {
+    let mut numbers = Vec::new();
+
+    for num_str in part.split_whitespace() {
+        let num = num_str
+            .parse()
+            .map_err(|_| ParseError::new(s, num_str, "invalid number"))?;
+        if numbers.contains(&num) {
+            return Err(ParseError::new(s, num_str, "duplicate number"));
+        }
+        numbers.push(num);
+    }
+
+    Ok(numbers)
+}
+
+/// Checks that the cards are numbered 1, 2, 3 and so on, as part two relies
+/// on a card's position to know which cards it wins copies of.
+fn check_ids(input: &str, cards: &[Card]) -> std::result::Result<(), ParseError> {
+    for ((i, line), card) in input.lines().enumerate().zip(cards) {
+        let expected = i as u32 + 1;
+        if card.id != expected {
+            let id_str = line
+                .split(':')
+                .next()
+                .unwrap_or(line)
+                .trim_start_matches("Card")
+                .trim_start();
+            return Err(
+                ParseError::new(line, id_str, format!("expected card {}", expected)).on_line(i + 1),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod day4_tests {
+    use super::*;
+    use inputs::{check_answers, check_examples, Part};
+
+    #[test]
+    fn part_1() {
+        check_answers::<Day4>(Part::One).unwrap();
+    }
+
+    #[test]
+    fn part_2() {
+        check_answers::<Day4>(Part::Two).unwrap();
+    }
+
+    #[test]
+    fn example_part_1() {
+        check_examples::<Day4>(Part::One).unwrap();
+    }
+
+    #[test]
+    fn example_part_2() {
+        check_examples::<Day4>(Part::Two).unwrap();
+    }
+
+    fn parse_error(input: &str) -> ParseError {
+        Day4::parse(input)
+            .unwrap_err()
+            .downcast::<ParseError>()
+            .unwrap()
+    }
+
+    #[test]
+    fn keeps_ids() {
+        let card: Card = "Card  12: 41 48 | 48 9 41".parse().unwrap();
+        assert_eq!((card.id(), card.matches()), (12, 2));
+    }
+
+    #[test]
+    fn reports_bad_numbers() {
+        let e = parse_error("Card 1: 41 4x | 48 9");
+        assert_eq!((e.message(), e.text()), ("invalid number", "4x"));
+
+        let e = parse_error("Card 1: 41 48 | 48 9 48");
+        assert_eq!(
+            (e.message(), e.text(), e.column()),
+            ("duplicate number", "48", 22)
+        );
+    }
+
+    #[test]
+    fn checks_ids_are_consecutive() {
+        let e = parse_error("Card 1: 1 | 2\nCard 3: 1 | 2");
+        assert_eq!(
+            (e.message(), e.line(), e.text()),
+            ("expected card 2", 2, "3")
+        );
+    }
+
+    #[test]
+    fn traces_copies() {
+        let input = &inputs::read_examples(4).unwrap()[0].input;
+        let copies = cascade(&Day4::parse(input).unwrap()).unwrap();
+
+        assert_eq!(
+            copies[3],
+            Copies {
+                id: 4,
+                copies: 8,
+                won_from: vec![(1, 1), (2, 2), (3, 4)],
+            }
+        );
+        assert_eq!(copies.iter().map(|won| won.copies).sum::<u64>(), 30);
+    }
+
+    #[test]
+    fn refuses_to_win_past_the_table() {
+        let cards = Day4::parse("Card 1: 1 2 | 1 2\nCard 2: 3 | 4").unwrap();
+        let e = cascade(&cards).unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "card 1 wins copies of the next 2 cards, but only 1 follow it"
+        );
+    }
+
+    #[test]
+    fn scores_without_overflow() {
+        let card = |matches: u32| {
+            let numbers: Vec<String> = (1..=matches).map(|n| n.to_string()).collect();
+            let numbers = numbers.join(" ");
+            format!("Card 1: {} | {}", numbers, numbers)
+        };
+
+        let cards = Day4::parse(&card(33)).unwrap();
+        assert_eq!(part_one(&cards).unwrap(), 1 << 32);
+        let cards = Day4::parse(&card(64)).unwrap();
+        assert_eq!(part_one(&cards).unwrap(), 1 << 63);
+
+        let cards = Day4::parse(&card(65)).unwrap();
+        assert!(part_one(&cards).is_err());
+        let twice = format!("{}\n{}", card(64), card(64).replace("Card 1", "Card 2"));
+        let cards = Day4::parse(&twice).unwrap();
+        assert!(part_one(&cards).is_err());
+    }
+}
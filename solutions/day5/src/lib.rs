@@ -0,0 +1,240 @@
+use anyhow::{anyhow, Result};
+use inputs::{Answer, Lines, ParseError, Solution};
+use regex::Regex;
+use std::ops::Range;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Parsed = Almanac;
+
+    fn parse(input: &str) -> Result<Almanac> {
+        let lines: Lines = input.lines().map(String::from).collect();
+        let seeds_line = lines.first().ok_or_else(|| anyhow!("no seeds line"))?;
+        let seeds = seeds_line
+            .strip_prefix("seeds:")
+            .ok_or_else(|| ParseError::new(seeds_line, &seeds_line[..0], "expected \"seeds:\""))?
+            .split_whitespace()
+            .map(|c| {
+                c.parse::<usize>()
+                    .map_err(|_| ParseError::new(seeds_line, c, "invalid seed"))
+            })
+            .collect::<Result<Vec<usize>, _>>()?;
+        // Part two reads the seeds as pairs of a start and a length.
+        if seeds.is_empty() {
+            return Err(ParseError::missing(seeds_line, "no seeds").into());
+        }
+        if seeds.len() % 2 == 1 {
+            let last = seeds_line.split_whitespace().last().unwrap_or(seeds_line);
+            return Err(ParseError::new(seeds_line, last, "seed range has no length").into());
+        }
+
+        Ok(Almanac {
+            seeds,
+            maps: parse_all_maps(&lines)?,
+        })
+    }
+
+    fn part_one(almanac: &Almanac) -> Result<Answer> {
+        part_one(almanac).map(Answer::from)
+    }
+
+    fn part_two(almanac: &Almanac) -> Result<Answer> {
+        part_two(almanac).map(Answer::from)
+    }
+}
+
+fn part_one(almanac: &Almanac) -> Result<usize> {
+    let seeds = almanac.seeds.clone();
+
+    let locations = almanac.maps.iter().fold(seeds, |seeds, map| {
+        seeds
+            .iter()
+            .map(|s| {
+                match map
+                    .ranges
+                    .iter()
+                    .find(|r| r.source.contains(s))
+                    .map(|r| r.dest.start + (s - r.source.start))
+                {
+                    Some(mapped) => mapped,
+                    None => *s,
+                }
+            })
+            .collect()
+    });
+
+    locations
+        .into_iter()
+        .min()
+        .ok_or_else(|| anyhow!("no seeds"))
+}
+
+fn part_two(almanac: &Almanac) -> Result<usize> {
+    let seeds = almanac
+        .seeds
+        .chunks(2)
+        .map(|pair| {
+            let start = pair[0];
+            let len = pair[1];
+
+            (start, start + len)
+        })
+        .collect::<Vec<_>>();
+
+    let locations = almanac.maps.iter().fold(seeds, |seeds, map| {
+        seeds
+            .iter()
+            .flat_map(|&(start, end)| {
+                let mut mapped = Vec::new();
+                let mut unmapped = vec![(start, end)];
+
+                for range in &map.ranges {
+                    let dst = range.dest.start;
+                    let src = range.source.start;
+                    let len = range.dest.end - range.dest.start;
+
+                    let mut m = Vec::new();
+                    for (start, end) in unmapped {
+                        let a = (start, end.min(src));
+                        let b = (start.max(src), (src + len).min(end));
+                        let c = ((src + len).max(start), end);
+                        if a.0 < a.1 {
+                            m.push(a);
+                        }
+                        if b.0 < b.1 {
+                            mapped.push((b.0 - src + dst, b.1 - src + dst));
+                        }
+                        if c.0 < c.1 {
+                            m.push(c);
+                        }
+                    }
+                    unmapped = m;
+                }
+
+                // Anything no range covered maps to itself
+                mapped.extend(unmapped);
+                mapped
+            })
+            .collect()
+    });
+
+    locations
+        .iter()
+        .map(|r| r.0)
+        .min()
+        .ok_or_else(|| anyhow!("no seeds"))
+}
+
+fn parse_all_maps(lines: &Lines) -> Result<Vec<Map>, ParseError> {
+    let map_regex = Regex::new(r"^(?P<source>\w+)-to-(?P<destination>\w+) map:$").unwrap();
+
+    let mut all_maps: Vec<Map> = Vec::new();
+    let mut curr_map = Map::default();
+
+    // The first line holds the seeds
+    for (i, line) in lines.iter().enumerate().skip(1) {
+        if map_regex.is_match(line) {
+            curr_map = Map { ranges: vec![] };
+        } else if line.trim().is_empty() {
+            if !curr_map.ranges.is_empty() {
+                all_maps.push(curr_map);
+                curr_map = Map::default();
+            }
+        } else {
+            let numbers: Vec<usize> = line
+                .split_whitespace()
+                .map(|n| {
+                    n.parse::<usize>()
+                        .map_err(|_| ParseError::new(line, n, "invalid number").on_line(i + 1))
+                })
+                .collect::<Result<_, _>>()?;
+            if numbers.len() != 3 {
+                return Err(ParseError::new(
+                    line,
+                    line,
+                    "expected a destination start, source start and length",
+                )
+                .on_line(i + 1));
+            }
+
+            let d_start = numbers[0];
+            let s_start = numbers[1];
+            let len = numbers[2];
+
+            curr_map.ranges.push(MapRange {
+                dest: (d_start..d_start + len).into(),
+                source: (s_start..s_start + len).into(),
+            });
+        }
+    }
+
+    if !curr_map.ranges.is_empty() {
+        all_maps.push(curr_map);
+    }
+
+    Ok(all_maps)
+}
+
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: Vec<usize>,
+    maps: Vec<Map>,
+}
+
+#[derive(Debug, Default)]
+struct Map {
+    ranges: Vec<MapRange>,
+}
+
+#[derive(Debug)]
+struct MapRange {
+    dest: Box<Range<usize>>,
+    source: Box<Range<usize>>,
+}
+
+#[cfg(test)]
+mod day5_tests {
+    use super::*;
+    use inputs::{check_answers, check_examples, Part};
+
+    #[test]
+    fn part_1() {
+        check_answers::<Day5>(Part::One).unwrap();
+    }
+
+    #[test]
+    fn part_2() {
+        check_answers::<Day5>(Part::Two).unwrap();
+    }
+
+    #[test]
+    fn example_part_1() {
+        check_examples::<Day5>(Part::One).unwrap();
+    }
+
+    #[test]
+    fn example_part_2() {
+        check_examples::<Day5>(Part::Two).unwrap();
+    }
+
+    #[test]
+    fn rejects_unpaired_seeds() {
+        let error = |input: &str| {
+            let e = Day5::parse(input).unwrap_err();
+            let e = e.downcast_ref::<ParseError>().unwrap();
+            (e.message().to_string(), e.line(), e.text().to_string())
+        };
+
+        assert_eq!(
+            error("seeds: 1 2 3\n"),
+            ("seed range has no length".to_string(), 1, "3".to_string())
+        );
+        assert_eq!(
+            error("seeds:\n"),
+            ("no seeds".to_string(), 1, String::new())
+        );
+    }
+}
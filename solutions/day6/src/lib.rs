@@ -0,0 +1,146 @@
+use anyhow::{anyhow, Result};
+use inputs::{Answer, Lines, ParseError, Solution};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Parsed = Sheet;
+
+    fn parse(input: &str) -> Result<Sheet> {
+        let lines: Lines = input.lines().map(String::from).collect();
+        if lines.len() < 2 {
+            return Err(anyhow!("expected a time line and a distance line"));
+        }
+
+        Ok(Sheet {
+            races: parse_races(&lines)?,
+            bad_kerning_race: parse_race_bad_kerning(&lines)?,
+        })
+    }
+
+    fn part_one(sheet: &Sheet) -> Result<Answer> {
+        part_one(sheet).map(Answer::from)
+    }
+
+    fn part_two(sheet: &Sheet) -> Result<Answer> {
+        part_two(sheet).map(Answer::from)
+    }
+}
+
+fn part_one(sheet: &Sheet) -> Result<usize> {
+    let num_ways = sheet
+        .races
+        .iter()
+        .map(|&(time, target_distance)| {
+            let mut num_ways_to_win_this_race = 0;
+            for hold_time in 1..=time {
+                if hold_time * (time - hold_time) > target_distance {
+                    num_ways_to_win_this_race += 1
+                }
+            }
+
+            num_ways_to_win_this_race
+        })
+        .collect::<Vec<_>>();
+
+    Ok(num_ways.iter().product())
+}
+
+fn part_two(sheet: &Sheet) -> Result<usize> {
+    let (time, target_distance) = sheet.bad_kerning_race;
+
+    let mut num_ways_to_win_this_race = 0;
+    for hold_time in 1..=time {
+        if hold_time * (time - hold_time) > target_distance {
+            num_ways_to_win_this_race += 1
+        }
+    }
+
+    Ok(num_ways_to_win_this_race)
+}
+
+/// The race sheet, read both as separate races and as one badly kerned race.
+#[derive(Debug)]
+pub struct Sheet {
+    races: Vec<(usize, usize)>,
+    bad_kerning_race: (usize, usize),
+}
+
+fn parse_races(lines: &Lines) -> Result<Vec<(usize, usize)>, ParseError> {
+    let times = parse_numbers(&lines[0], "Time:").map_err(|e| e.on_line(1))?;
+    let distances = parse_numbers(&lines[1], "Distance:").map_err(|e| e.on_line(2))?;
+
+    if times.len() != distances.len() {
+        return Err(ParseError::new(
+            &lines[1],
+            &lines[1],
+            format!("expected {} distances, one for each time", times.len()),
+        )
+        .on_line(2));
+    }
+
+    Ok(times.into_iter().zip(distances).collect())
+}
+
+fn parse_race_bad_kerning(lines: &Lines) -> Result<(usize, usize), ParseError> {
+    let time = parse_kerned_number(&lines[0], "Time:").map_err(|e| e.on_line(1))?;
+    let distance = parse_kerned_number(&lines[1], "Distance:").map_err(|e| e.on_line(2))?;
+
+    Ok((time, distance))
+}
+
+/// The whitespace-separated numbers following `label` on `line`.
+fn parse_numbers(line: &str, label: &str) -> Result<Vec<usize>, ParseError> {
+    strip_label(line, label)?
+        .split_whitespace()
+        .map(|t| {
+            t.parse::<usize>()
+                .map_err(|_| ParseError::new(line, t, "invalid number"))
+        })
+        .collect()
+}
+
+/// The numbers following `label` on `line`, read as one number with the
+/// spaces between them ignored.
+fn parse_kerned_number(line: &str, label: &str) -> Result<usize, ParseError> {
+    let numbers = strip_label(line, label)?.trim();
+
+    numbers
+        .split_whitespace()
+        .fold(String::new(), |acc, part| acc + part)
+        .parse::<usize>()
+        .map_err(|_| ParseError::new(line, numbers, "invalid number"))
+}
+
+fn strip_label<'a>(line: &'a str, label: &str) -> Result<&'a str, ParseError> {
+    line.strip_prefix(label)
+        .ok_or_else(|| ParseError::new(line, &line[..0], format!("expected \"{}\"", label)))
+}
+
+#[cfg(test)]
+mod day6_tests {
+    use super::*;
+    use inputs::{check_answers, check_examples, Part};
+
+    #[test]
+    fn part_1() {
+        check_answers::<Day6>(Part::One).unwrap();
+    }
+
+    #[test]
+    fn part_2() {
+        check_answers::<Day6>(Part::Two).unwrap();
+    }
+
+    #[test]
+    fn example_part_1() {
+        check_examples::<Day6>(Part::One).unwrap();
+    }
+
+    #[test]
+    fn example_part_2() {
+        check_examples::<Day6>(Part::Two).unwrap();
+    }
+}
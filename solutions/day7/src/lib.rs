@@ -0,0 +1,197 @@
+use crate::Score::{FiveOfKind, FourOfKind, FullHouse, HighCard, OnePair, ThreeOfKind, TwoPair};
+use anyhow::Result;
+use inputs::{parse_lines, Answer, ParseError, Solution};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Parsed = Vec<Hand>;
+
+    fn parse(input: &str) -> Result<Vec<Hand>> {
+        Ok(parse_lines(input, Hand::from_str)?)
+    }
+
+    fn part_one(hands: &Vec<Hand>) -> Result<Answer> {
+        part_one(hands).map(Answer::from)
+    }
+
+    fn part_two(hands: &Vec<Hand>) -> Result<Answer> {
+        part_two(hands).map(Answer::from)
+    }
+}
+
+fn part_one(hands: &[Hand]) -> Result<usize> {
+    calculate_total_winnings(hands, false)
+}
+
+fn part_two(hands: &[Hand]) -> Result<usize> {
+    calculate_total_winnings(hands, true)
+}
+
+fn calculate_total_winnings(hands: &[Hand], jokers: bool) -> Result<usize> {
+    let cards = if !jokers { CARDS_1 } else { CARDS_2 };
+
+    let mut hands = hands
+        .iter()
+        .map(|h| (Hand::calc_score(&h.cards, jokers), h))
+        .collect::<Vec<_>>();
+
+    sort(&mut hands, cards);
+
+    let total_hands = hands.len();
+    let total_winnings = hands
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (i, (_, h))| acc + (h.bid * (total_hands - i)));
+
+    Ok(total_winnings)
+}
+
+const CARDS_1: [char; 13] = [
+    'A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2',
+];
+
+const CARDS_2: [char; 13] = [
+    'A', 'K', 'Q', 'T', '9', '8', '7', '6', '5', '4', '3', '2', 'J',
+];
+
+fn sort(hands: &mut [(Score, &Hand)], cards: [char; 13]) {
+    hands.sort_by(|(s1, h1), (s2, h2)| {
+        if s1 > s2 {
+            Ordering::Greater
+        } else if s1 < s2 {
+            Ordering::Less
+        } else {
+            for i in 0..h1.cards.len() {
+                let h1c = cards.iter().position(|c| c == &h1.cards[i]).unwrap();
+                let h2c = cards.iter().position(|c| c == &h2.cards[i]).unwrap();
+
+                if h1c > h2c {
+                    return Ordering::Greater;
+                } else if h1c < h2c {
+                    return Ordering::Less;
+                }
+            }
+            Ordering::Equal
+        }
+    })
+}
+
+#[derive(PartialEq, PartialOrd, Eq, Ord, Debug)]
+enum Score {
+    FiveOfKind,
+    FourOfKind,
+    FullHouse,
+    ThreeOfKind,
+    TwoPair,
+    OnePair,
+    HighCard,
+}
+
+#[derive(Debug)]
+pub struct Hand {
+    cards: Vec<char>,
+    bid: usize,
+}
+
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut iter = s.split_whitespace();
+        let cards_str = iter
+            .next()
+            .ok_or_else(|| ParseError::missing(s, "no cards"))?;
+        if let Some((i, c)) = cards_str.char_indices().find(|(_, c)| !CARDS_1.contains(c)) {
+            let card = &cards_str[i..i + c.len_utf8()];
+            return Err(ParseError::new(s, card, "unknown card"));
+        }
+        let cards = cards_str.chars().collect::<Vec<_>>();
+        if cards.len() != 5 {
+            return Err(ParseError::new(s, cards_str, "expected 5 cards"));
+        }
+
+        let bid_str = iter
+            .next()
+            .ok_or_else(|| ParseError::missing(s, "no bid"))?;
+        let bid = bid_str
+            .parse::<usize>()
+            .map_err(|_| ParseError::new(s, bid_str, "invalid bid"))?;
+
+        Ok(Self { cards, bid })
+    }
+}
+
+impl Hand {
+    fn calc_score(cards: &[char], jokers: bool) -> Score {
+        let mut char_counts = HashMap::new();
+
+        for c in cards {
+            *char_counts.entry(c).or_insert(0) += 1;
+        }
+
+        if jokers {
+            let joker_count = *char_counts.entry(&'J').or_default();
+            if joker_count > 0 {
+                let mut highest_char = None;
+                let mut highest_value = 0;
+
+                for (&key, &value) in char_counts.iter() {
+                    if value > highest_value && key != &'J' {
+                        highest_value = value;
+                        highest_char = Some(key);
+                    }
+                }
+
+                if let Some(c) = highest_char {
+                    *char_counts.entry(c).or_insert(0) += joker_count;
+                    char_counts.remove(&'J');
+                };
+            }
+        }
+
+        let mut counts: Vec<i32> = char_counts.values().cloned().collect();
+        counts.sort_by(|c1, c2| c2.cmp(c1));
+
+        match counts {
+            c if c.starts_with(&[5]) => FiveOfKind,
+            c if c.starts_with(&[4]) => FourOfKind,
+            c if c.starts_with(&[3, 2]) => FullHouse,
+            c if c.starts_with(&[3]) => ThreeOfKind,
+            c if c.starts_with(&[2, 2]) => TwoPair,
+            c if c.starts_with(&[2]) => OnePair,
+            _ => HighCard,
+        }
+    }
+}
+
+#[cfg(test)]
+mod day7_tests {
+    use super::*;
+    use inputs::{check_answers, check_examples, Part};
+
+    #[test]
+    fn part_1() {
+        check_answers::<Day7>(Part::One).unwrap();
+    }
+
+    #[test]
+    fn part_2() {
+        check_answers::<Day7>(Part::Two).unwrap();
+    }
+
+    #[test]
+    fn example_part_1() {
+        check_examples::<Day7>(Part::One).unwrap();
+    }
+
+    #[test]
+    fn example_part_2() {
+        check_examples::<Day7>(Part::Two).unwrap();
+    }
+}
@@ -0,0 +1,229 @@
+use anyhow::{anyhow, Result};
+use inputs::{Answer, Lines, ParseError, Solution};
+use std::collections::HashMap;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Parsed = Map;
+
+    fn parse(input: &str) -> Result<Map> {
+        let lines: Lines = input.lines().map(String::from).collect();
+        parse(&lines)
+    }
+
+    fn part_one(map: &Map) -> Result<Answer> {
+        part_one(map).map(Answer::from)
+    }
+
+    fn part_two(map: &Map) -> Result<Answer> {
+        part_two(map).map(Answer::from)
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    directions: Vec<char>,
+    network: HashMap<String, (String, String)>,
+}
+
+fn part_one(map: &Map) -> Result<usize> {
+    let Map {
+        directions,
+        network,
+    } = map;
+
+    for node in ["AAA", "ZZZ"] {
+        if !network.contains_key(node) {
+            return Err(anyhow!("no {} node", node));
+        }
+    }
+
+    let mut count = 0;
+    let mut curr_node = "AAA";
+
+    while curr_node != "ZZZ" {
+        for char in directions {
+            let path = &network[curr_node];
+            if char == &'L' {
+                curr_node = path.0.as_str();
+            } else {
+                curr_node = path.1.as_str();
+            }
+            count += 1;
+
+            if curr_node == "ZZZ" {
+                break;
+            }
+        }
+    }
+
+    Ok(count)
+}
+
+fn part_two(map: &Map) -> Result<usize> {
+    let Map {
+        directions,
+        network,
+    } = map;
+
+    let nodes: Vec<&str> = network
+        .keys()
+        .filter(|k| k.ends_with('A'))
+        .map(String::as_str)
+        .collect();
+
+    let multiples = nodes
+        .iter()
+        .map(|n| {
+            let mut first_z_encounter: Option<usize> = None;
+            let mut count = 0;
+            let mut iterate = true;
+            let mut node = *n;
+
+            while iterate {
+                for c in directions {
+                    let (l, r) = &network[node];
+                    if c == &'L' {
+                        node = l.as_str();
+                    } else {
+                        node = r.as_str();
+                    }
+
+                    count += 1;
+
+                    if node.ends_with("Z") {
+                        if first_z_encounter.is_some() {
+                            iterate = false;
+                        } else {
+                            first_z_encounter = Some(count);
+                        }
+                    }
+                }
+            }
+
+            count - first_z_encounter.unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    Ok(lcm_of_vec(&multiples))
+}
+
+fn parse(lines: &Lines) -> Result<Map> {
+    let mut iter = lines.iter().enumerate();
+    let (_, directions_line) = iter.next().ok_or_else(|| anyhow!("no directions line"))?;
+    if directions_line.is_empty() {
+        return Err(ParseError::missing(directions_line, "no directions").into());
+    }
+    if let Some((i, c)) = directions_line
+        .char_indices()
+        .find(|&(_, c)| c != 'L' && c != 'R')
+    {
+        let direction = &directions_line[i..i + c.len_utf8()];
+        return Err(ParseError::new(directions_line, direction, "expected L or R").into());
+    }
+    let directions = directions_line.chars().collect();
+    let _ = iter.next();
+
+    let nodes = iter
+        .map(|(i, line)| {
+            let node = parse_node(line).map_err(|e| e.on_line(i + 1))?;
+            Ok((i, line, node))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+    let network: HashMap<String, (String, String)> = nodes
+        .iter()
+        .map(|(_, _, (node, l, r))| (node.to_string(), (l.to_string(), r.to_string())))
+        .collect();
+
+    // Every path has to lead somewhere, so that walking the map can't get lost.
+    for (i, line, (_, l, r)) in &nodes {
+        if let Some(path) = [l, r]
+            .into_iter()
+            .find(|path| !network.contains_key(**path))
+        {
+            return Err(ParseError::new(line, path, "undefined node")
+                .on_line(i + 1)
+                .into());
+        }
+    }
+
+    Ok(Map {
+        directions,
+        network,
+    })
+}
+
+/// Splits a line like `AAA = (BBB, CCC)` into the node and its left and right paths.
+fn parse_node(line: &str) -> Result<(&str, &str, &str), ParseError> {
+    let (node, paths) = line
+        .split_once(" = ")
+        .ok_or_else(|| ParseError::missing(line, "expected \" = \""))?;
+    let paths_inner = paths
+        .strip_prefix('(')
+        .and_then(|p| p.strip_suffix(')'))
+        .ok_or_else(|| ParseError::new(line, paths, "expected \"(LEFT, RIGHT)\""))?;
+    let (l, r) = paths_inner
+        .split_once(", ")
+        .ok_or_else(|| ParseError::new(line, paths_inner, "expected \"LEFT, RIGHT\""))?;
+
+    Ok((node, l, r))
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    a / gcd(a, b) * b
+}
+
+fn lcm_of_vec(numbers: &[usize]) -> usize {
+    numbers.iter().copied().fold(1, lcm)
+}
+
+#[cfg(test)]
+mod day8_tests {
+    use super::*;
+    use inputs::{check_answers, check_examples, Part};
+
+    #[test]
+    fn part_1() {
+        check_answers::<Day8>(Part::One).unwrap();
+    }
+
+    #[test]
+    fn part_2() {
+        check_answers::<Day8>(Part::Two).unwrap();
+    }
+
+    #[test]
+    fn example_part_1() {
+        check_examples::<Day8>(Part::One).unwrap();
+    }
+
+    #[test]
+    fn example_part_2() {
+        check_examples::<Day8>(Part::Two).unwrap();
+    }
+
+    #[test]
+    fn rejects_missing_nodes() {
+        let e = Day8::parse("LR\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, CCC)\n").unwrap_err();
+        let e = e.downcast_ref::<ParseError>().unwrap();
+        assert_eq!(
+            (e.message(), e.line(), e.text()),
+            ("undefined node", 3, "BBB")
+        );
+
+        let ghosts = Day8::parse("LR\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)\n").unwrap();
+        assert!(part_one(&ghosts).is_err());
+        assert!(part_two(&ghosts).is_ok());
+    }
+}
@@ -0,0 +1,101 @@
+use anyhow::Result;
+use inputs::{parse_lines, Answer, ParseError, Solution};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Parsed = Vec<Vec<isize>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<isize>>> {
+        Ok(parse_lines(input, |l| {
+            if l.trim().is_empty() {
+                return Err(ParseError::missing(l, "no numbers"));
+            }
+
+            l.split_whitespace()
+                .map(|c| {
+                    c.parse::<isize>()
+                        .map_err(|_| ParseError::new(l, c, "invalid number"))
+                })
+                .collect()
+        })?)
+    }
+
+    fn part_one(sequences: &Vec<Vec<isize>>) -> Result<Answer> {
+        part_one(sequences).map(Answer::from)
+    }
+
+    fn part_two(sequences: &Vec<Vec<isize>>) -> Result<Answer> {
+        part_two(sequences).map(Answer::from)
+    }
+}
+
+fn part_one(sequences: &[Vec<isize>]) -> Result<isize> {
+    extrapolated_sum(sequences, true)
+}
+
+fn part_two(sequences: &[Vec<isize>]) -> Result<isize> {
+    extrapolated_sum(sequences, false)
+}
+
+fn extrapolated_sum(sequences: &[Vec<isize>], forwards: bool) -> Result<isize> {
+    Ok(sequences
+        .iter()
+        .map(|nums| {
+            let mut history: Vec<Vec<isize>> = vec![];
+            history.push(nums.clone());
+
+            loop {
+                let mut sequence: Vec<isize> = vec![];
+                if let Some(last) = history.last() {
+                    for w in last.windows(2) {
+                        sequence.push(w[1] - w[0]);
+                    }
+                    history.push(sequence.clone());
+                } else {
+                    break;
+                }
+
+                if sequence.iter().all(|v| v == &0) {
+                    break;
+                }
+            }
+
+            history.iter().rev().fold(0, |acc, s| {
+                if forwards {
+                    acc + s[s.len() - 1]
+                } else {
+                    s[0] - acc
+                }
+            })
+        })
+        .sum())
+}
+
+#[cfg(test)]
+mod day9_tests {
+    use super::*;
+    use inputs::{check_answers, check_examples, Part};
+
+    #[test]
+    fn part_1() {
+        check_answers::<Day9>(Part::One).unwrap();
+    }
+
+    #[test]
+    fn part_2() {
+        check_answers::<Day9>(Part::Two).unwrap();
+    }
+
+    #[test]
+    fn example_part_1() {
+        check_examples::<Day9>(Part::One).unwrap();
+    }
+
+    #[test]
+    fn example_part_2() {
+        check_examples::<Day9>(Part::Two).unwrap();
+    }
+}